@@ -0,0 +1,117 @@
+//! Conversions between satoshi amounts and the decimal coin amounts used by the node's JSON.
+//!
+//! The node formats amounts as decimal numbers with up to eight decimal places. Going through
+//! `f64` silently loses precision for large values, so amounts are converted from and to their
+//! decimal text instead.
+
+/// Number of satoshis in one coin.
+const SATS_PER_COIN: i64 = 100_000_000;
+
+/// Number of decimal places in a coin amount.
+const DECIMALS: usize = 8;
+
+/// Parses a decimal coin amount (e.g. `"21000000.00000001"`) into satoshis.
+pub(crate) fn parse_btc_amount(amount: &str) -> Result<i64, String> {
+    let invalid = || format!("invalid amount: {}", amount);
+
+    let (negative, digits) = match amount.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, amount),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    // Digits beyond the eighth decimal place are only acceptable if they are zero
+    let (fraction, excess) = fraction.split_at(fraction.len().min(DECIMALS));
+    if excess.bytes().any(|b| b != b'0') {
+        return Err(format!(
+            "amount has more than {} decimals: {}",
+            DECIMALS, amount
+        ));
+    }
+
+    let whole: i64 = whole.parse().map_err(|_| invalid())?;
+    let fraction: i64 = if fraction.is_empty() {
+        0
+    } else {
+        // Right-pad to eight digits, e.g. "5" is 50000000 satoshis
+        fraction.parse::<i64>().map_err(|_| invalid())?
+            * 10i64.pow((DECIMALS - fraction.len()) as u32)
+    };
+
+    let sats = whole
+        .checked_mul(SATS_PER_COIN)
+        .and_then(|sats| sats.checked_add(fraction))
+        .ok_or_else(|| format!("amount out of range: {}", amount))?;
+    Ok(if negative { -sats } else { sats })
+}
+
+/// Formats a satoshi amount as a decimal coin amount with eight decimal places.
+pub(crate) fn format_btc_amount(sats: u64) -> String {
+    let per_coin = SATS_PER_COIN as u64;
+    format!(
+        "{}.{:0width$}",
+        sats / per_coin,
+        sats % per_coin,
+        width = DECIMALS
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_btc_amount() {
+        assert_eq!(format_btc_amount(0), "0.00000000");
+        assert_eq!(format_btc_amount(1), "0.00000001");
+        assert_eq!(format_btc_amount(100_000_000), "1.00000000");
+        assert_eq!(
+            format_btc_amount(2_100_000_000_000_001),
+            "21000000.00000001"
+        );
+        assert_eq!(format_btc_amount(u64::MAX), "184467440737.09551615");
+
+        // Round trip
+        for sats in [0, 1, 12_345_678, 2_099_999_999_999_999] {
+            assert_eq!(parse_btc_amount(&format_btc_amount(sats)), Ok(sats as i64));
+        }
+    }
+
+    #[test]
+    fn test_parse_btc_amount() {
+        assert_eq!(parse_btc_amount("0"), Ok(0));
+        assert_eq!(parse_btc_amount("1"), Ok(100_000_000));
+        assert_eq!(parse_btc_amount("0.00000546"), Ok(546));
+        assert_eq!(parse_btc_amount("12.5"), Ok(1_250_000_000));
+        assert_eq!(parse_btc_amount("-0.0000001"), Ok(-10));
+        assert_eq!(parse_btc_amount("0.100000000"), Ok(10_000_000));
+    }
+
+    #[test]
+    fn test_parse_btc_amount_is_exact() {
+        // Both of these lose the final satoshi if parsed through f64
+        assert_eq!(
+            parse_btc_amount("21000000.00000001"),
+            Ok(2_100_000_000_000_001)
+        );
+        assert_eq!(
+            parse_btc_amount("20999999.99999999"),
+            Ok(2_099_999_999_999_999)
+        );
+    }
+
+    #[test]
+    fn test_parse_btc_amount_rejects_invalid() {
+        assert!(parse_btc_amount("").is_err());
+        assert!(parse_btc_amount(".5").is_err());
+        assert!(parse_btc_amount("1.2.3").is_err());
+        assert!(parse_btc_amount("1e-8").is_err());
+        assert!(parse_btc_amount("0.000000001").is_err());
+        assert!(parse_btc_amount("99999999999999999999").is_err());
+    }
+}
@@ -1,10 +1,23 @@
 //! Bitcoin SV node client implementation.
 
-use crate::error::Result;
-use crate::rest::RestClient;
-use crate::rpc::RpcClient;
+use crate::error::{Error, Result};
+use crate::rest::{RestClient, RestFormat};
+use crate::rpc::{invalid_block_verbosity, RpcClient, RPC_METHOD_NOT_FOUND};
+use crate::types::{
+    AddNodeCommand, AddedNodeInfo, AddressGroupEntry, AddressValidation, BanCommand, BannedEntry,
+    BlacklistEntry, BlacklistResult, BlockData, BlockHeaderInfo, BlockInfo, BlockStats,
+    BlockStatsTarget, BlockTemplate, BlockTemplateRequest, BlockchainInfo, ChainTip, ChainTxStats,
+    DecodedBlock, DecodedTransaction, DisconnectTarget, FeeRate, FrozenTxo, FundRawOptions,
+    FundRawResult, JournalCheckResult, MemoryInfo, MempoolEntry, MempoolInfo, MerkleProof,
+    MiningCandidate, MiningInfo, MiningSolution, MultisigInfo, NetTotals, NodeInfo, PeerInfo,
+    RawTxSubmission, ReceivedByAddress, SafeModeInfo, ScriptCheck, ScriptCheckResult,
+    SendRawTransactionsResult, TargetType, TipInfo, TransactionInfo, TscMerkleProof, TxInput,
+    TxOutInfo, TxOutsEntry, Unspent, UtxoSetResult, WalletInfo, WalletTransaction, WalletTxInfo,
+};
 use async_trait::async_trait;
-use bitcoinsv::bitcoin::{Block, BlockHash, BlockHeader};
+use bitcoinsv::bitcoin::{Block, BlockHash, BlockHeader, Encodable, Tx, TxHash};
+use std::collections::HashMap;
+use std::time::Duration;
 
 /// Trait for communicating with a Bitcoin node.
 ///
@@ -16,6 +29,29 @@ pub trait NodeClient {
     /// Returns the hash of the best (tip) block in the longest blockchain.
     async fn get_best_block_hash(&self) -> Result<BlockHash>;
 
+    /// Returns the number of blocks in the longest blockchain (the height of the tip).
+    async fn get_block_count(&self) -> Result<u64>;
+
+    /// Returns the hash of the block at the specified height in the longest blockchain.
+    ///
+    /// This is a single `getblockhash` call, which every node version provides, so no fallback
+    /// is needed. For the hashes of many consecutive heights, fetching up to 2000 headers per
+    /// request with [`SvNodeClient::get_block_headers`](crate::SvNodeClient::get_block_headers)
+    /// saves a round trip per block.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The height of the block
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BlockHeightOutOfRange`](crate::Error::BlockHeightOutOfRange) if the
+    /// height is beyond the current tip.
+    async fn get_block_hash(&self, height: u64) -> Result<BlockHash>;
+
+    /// Returns the current proof-of-work difficulty as a multiple of the minimum difficulty.
+    async fn get_difficulty(&self) -> Result<f64>;
+
     /// Returns the block header for the specified block hash.
     ///
     /// # Arguments
@@ -31,6 +67,32 @@ pub trait NodeClient {
     async fn get_block(&self, block_hash: &BlockHash) -> Result<Block>;
 }
 
+/// Trait for operational management of a Bitcoin SV node.
+///
+/// These calls are for node operators rather than for reading the blockchain, so they are kept
+/// separate from [`NodeClient`]. Import this trait to use its methods on [`SvNodeClient`].
+#[async_trait]
+pub trait NodeManagement {
+    /// Checks that the mempool journal used for block assembly is consistent with the mempool.
+    async fn check_journal(&self) -> Result<JournalCheckResult>;
+
+    /// Rebuilds the mempool journal from the mempool.
+    ///
+    /// Use this to recover if [`check_journal`](Self::check_journal) reports errors.
+    async fn rebuild_journal(&self) -> Result<()>;
+
+    /// Asks the node to shut down cleanly, returning the node's message.
+    ///
+    /// The node may close the connection while it answers; this is treated as success once the
+    /// node has responded with a success status.
+    async fn stop(&self) -> Result<String>;
+
+    /// Returns how long the node has been running.
+    ///
+    /// A value lower than in a previous sample means the node has restarted in between.
+    async fn uptime(&self) -> Result<Duration>;
+}
+
 /// Client for communicating with a Bitcoin SV node.
 ///
 /// This client manages both JSON-RPC and REST API connections to a Bitcoin SV node.
@@ -87,40 +149,2038 @@ impl SvNodeClient {
 
         Ok(Self { rpc, rest })
     }
-}
 
-#[async_trait]
-impl NodeClient for SvNodeClient {
-    async fn get_best_block_hash(&self) -> Result<BlockHash> {
-        self.rpc.get_best_block_hash().await
+    /// Returns a client that requests blocks, transactions and headers from the REST
+    /// interface in the given format.
+    ///
+    /// Binary is the default. Hex doubles the size of each response but passes intact through
+    /// reverse proxies that mangle binary bodies. The new client is a cheap clone that shares
+    /// this client's connections.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The format to request
+    pub fn with_rest_format(&self, format: RestFormat) -> SvNodeClient {
+        SvNodeClient {
+            rpc: self.rpc.clone(),
+            rest: self.rest.with_format(format),
+        }
     }
 
-    async fn get_block_header(&self, block_hash: &BlockHash) -> Result<BlockHeader> {
-        self.rpc.get_block_header(block_hash).await
+    /// Returns the transaction with the specified id.
+    ///
+    /// Uses the JSON-RPC interface and parses the transaction with the bitcoinsv crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The id of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the node doesn't have the transaction. Unless the node
+    /// runs with `-txindex`, it can only return transactions that are in the mempool or that
+    /// still have unspent outputs.
+    pub async fn get_raw_transaction(&self, txid: &TxHash) -> Result<Tx> {
+        self.rpc.get_raw_transaction(txid).await
     }
 
-    async fn get_block(&self, block_hash: &BlockHash) -> Result<Block> {
-        self.rest.get_block(block_hash).await
+    /// Returns a transaction decoded by the node, with its confirmation metadata.
+    ///
+    /// Output values are in satoshis. The serialized transaction is kept in
+    /// [`TransactionInfo::hex`] so it can be parsed with the bitcoinsv crate if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The id of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the node doesn't have the transaction, as for
+    /// [`get_raw_transaction`](Self::get_raw_transaction).
+    pub async fn get_raw_transaction_info(&self, txid: &TxHash) -> Result<TransactionInfo> {
+        self.rpc.get_raw_transaction_info(txid).await
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Broadcasts a hex-encoded transaction, returning its id.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The serialized transaction as hex
+    /// * `allow_high_fees` - Accept the transaction even if its fee is unusually high
+    /// * `dont_check_fee` - Skip the node's minimum fee check (the transaction is still subject
+    ///   to the node's mempool policy)
+    ///
+    /// # Errors
+    ///
+    /// If the node rejects the transaction (e.g. missing inputs or a mempool conflict) the
+    /// node's error code and message are returned unchanged as [`Error::Rpc`].
+    pub async fn send_raw_transaction(
+        &self,
+        tx_hex: &str,
+        allow_high_fees: bool,
+        dont_check_fee: bool,
+    ) -> Result<TxHash> {
+        self.rpc
+            .send_raw_transaction(tx_hex, allow_high_fees, dont_check_fee)
+            .await
+    }
 
-    #[test]
-    fn test_client_creation() {
-        let client = SvNodeClient::new(
-            "http://localhost:8332",
-            Some("user".to_string()),
-            Some("password".to_string()),
-        );
-        assert!(client.is_ok());
+    /// Broadcasts a batch of transactions in a single call using the SV `sendrawtransactions` RPC.
+    ///
+    /// The result lists the transactions that were already known, evicted or rejected; all
+    /// others were accepted. Submissions are serialized directly into the request body, so large
+    /// batches don't need an intermediate copy of every transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `txs` - The transactions to submit, each with its own flags
+    pub async fn send_raw_transactions(
+        &self,
+        txs: &[RawTxSubmission],
+    ) -> Result<SendRawTransactionsResult> {
+        self.rpc.send_raw_transactions(txs).await
     }
 
-    #[test]
-    fn test_client_creation_no_auth() {
-        let client = SvNodeClient::new("http://localhost:8332", None, None);
-        assert!(client.is_ok());
+    /// Broadcasts a transaction, returning its id.
+    ///
+    /// The transaction is serialized with the bitcoinsv crate and sent with
+    /// `sendrawtransaction` using the node's default fee checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The transaction to broadcast
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Rpc`] if the node rejects the transaction, and [`Error::TxidMismatch`] if
+    /// the txid returned by the node doesn't match `tx.hash()`.
+    pub async fn broadcast_transaction(&self, tx: &Tx) -> Result<TxHash> {
+        let mut bytes = Vec::with_capacity(tx.encoded_size() as usize);
+        tx.to_binary(&mut bytes)
+            .map_err(|e| Error::BitcoinSv(format!("Failed to serialize transaction: {}", e)))?;
+
+        let txid = self
+            .rpc
+            .send_raw_transaction(&hex::encode(bytes), false, false)
+            .await?;
+        let expected = tx.hash();
+        if txid != expected {
+            return Err(Error::TxidMismatch {
+                expected,
+                actual: txid,
+            });
+        }
+        Ok(txid)
+    }
+
+    /// Creates an unsigned transaction spending the given outputs, returned as hex.
+    ///
+    /// The transaction isn't stored or broadcast by the node. Once signed it can be sent with
+    /// [`send_raw_transaction`](Self::send_raw_transaction).
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The outputs to spend
+    /// * `outputs` - Address and amount in satoshis of each output, in transaction order
+    /// * `locktime` - Lock time of the transaction (the node uses zero if `None`)
+    pub async fn create_raw_transaction(
+        &self,
+        inputs: &[TxInput],
+        outputs: &[(String, u64)],
+        locktime: Option<u32>,
+    ) -> Result<String> {
+        self.rpc
+            .create_raw_transaction(inputs, outputs, locktime)
+            .await
+    }
+
+    /// Decodes a hex-encoded transaction using the node's decoder.
+    ///
+    /// Useful for inspecting scripts: each output includes the locking script in assembly form
+    /// and any addresses it pays to. Values are in satoshis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The serialized transaction as hex
+    pub async fn decode_raw_transaction(&self, tx_hex: &str) -> Result<DecodedTransaction> {
+        self.rpc.decode_raw_transaction(tx_hex).await
+    }
+
+    /// Adds inputs from the node's wallet to a transaction, and a change output if needed.
+    ///
+    /// The existing inputs and outputs are kept. The funded transaction is not signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The transaction to fund, as hex
+    /// * `options` - Change and fee options; [`FundRawOptions::default()`] uses the wallet's
+    ///   defaults
+    ///
+    /// # Errors
+    ///
+    /// Wallet errors such as insufficient funds are returned as [`Error::Rpc`] with the node's
+    /// code and message.
+    pub async fn fund_raw_transaction(
+        &self,
+        tx_hex: &str,
+        options: FundRawOptions,
+    ) -> Result<FundRawResult> {
+        self.rpc.fund_raw_transaction(tx_hex, &options).await
+    }
+
+    /// Returns an unspent transaction output, or `None` if it is spent or doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - Id of the transaction containing the output
+    /// * `vout` - Index of the output
+    /// * `include_mempool` - Whether to take the mempool into account; if `true`, outputs spent
+    ///   by mempool transactions are treated as spent and outputs of mempool transactions are
+    ///   found
+    pub async fn get_tx_out(
+        &self,
+        txid: &TxHash,
+        vout: u32,
+        include_mempool: bool,
+    ) -> Result<Option<TxOutInfo>> {
+        self.rpc.get_tx_out(txid, vout, include_mempool).await
+    }
+
+    /// Returns many unspent transaction outputs in a single call using the SV `gettxouts` RPC.
+    ///
+    /// The result has one entry per outpoint, in the same order, with `None` for outputs that
+    /// are spent or don't exist. Note that `gettxouts` reports a different set of fields than
+    /// [`get_tx_out`](Self::get_tx_out).
+    ///
+    /// # Arguments
+    ///
+    /// * `outpoints` - The transaction id and output index of each output
+    /// * `return_fields` - The fields to return, any of `scriptPubKey`, `scriptPubKeyLen`,
+    ///   `value`, `isStandard` and `confirmations` (all fields if `None`)
+    /// * `include_mempool` - Whether to take the mempool into account
+    pub async fn get_tx_outs(
+        &self,
+        outpoints: &[(TxHash, u32)],
+        return_fields: Option<&[&str]>,
+        include_mempool: bool,
+    ) -> Result<Vec<Option<TxOutsEntry>>> {
+        self.rpc
+            .get_tx_outs(outpoints, return_fields, include_mempool)
+            .await
+    }
+
+    /// Returns a serialized merkle block proving that the given transactions are in a block.
+    ///
+    /// # Arguments
+    ///
+    /// * `txids` - The transactions to prove, which must all be in the same block
+    /// * `block_hash` - The block to look in; if `None`, the node finds the block using an
+    ///   unspent output of the transactions or its `-txindex`
+    ///
+    /// # Errors
+    ///
+    /// If the transactions are not found in the block, the node's error is returned as
+    /// [`Error::Rpc`] with code -5, so the call can be retried against a different block.
+    pub async fn get_tx_out_proof(
+        &self,
+        txids: &[TxHash],
+        block_hash: Option<&BlockHash>,
+    ) -> Result<Vec<u8>> {
+        self.rpc.get_tx_out_proof(txids, block_hash).await
+    }
+
+    /// Verifies a serialized merkle block, returning the ids of the transactions it proves.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A merkle block as returned by [`get_tx_out_proof`](Self::get_tx_out_proof)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidProof`] if the proof doesn't match the merkle root of its block.
+    /// The node reports this as an empty list; it is returned as an error so that a tampered
+    /// proof can't be mistaken for a valid one. If the block is not in the node's best chain,
+    /// the node's error is returned as [`Error::Rpc`].
+    pub async fn verify_tx_out_proof(&self, proof: &[u8]) -> Result<Vec<TxHash>> {
+        self.rpc.verify_tx_out_proof(proof).await
+    }
+
+    /// Returns a proof that a transaction is included in a block, using the SV `getmerkleproof`
+    /// RPC.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The transaction to prove
+    /// * `block_hash` - The block containing the transaction; if `None`, the node finds the
+    ///   block using an unspent output of the transaction or its `-txindex`
+    pub async fn get_merkle_proof(
+        &self,
+        txid: &TxHash,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<MerkleProof> {
+        self.rpc.get_merkle_proof(txid, block_hash).await
+    }
+
+    /// Returns a proof that a transaction is included in a block in the TSC standard format,
+    /// using the SV `getmerkleproof2` RPC.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The block containing the transaction
+    /// * `txid` - The transaction to prove
+    /// * `include_full_tx` - Include the full transaction in the proof instead of its id
+    /// * `target_type` - Whether to identify the block by its hash, header or merkle root
+    pub async fn get_merkle_proof2(
+        &self,
+        block_hash: &BlockHash,
+        txid: &TxHash,
+        include_full_tx: bool,
+        target_type: TargetType,
+    ) -> Result<TscMerkleProof> {
+        self.rpc
+            .get_merkle_proof2(block_hash, txid, include_full_tx, target_type)
+            .await
+    }
+
+    /// Verifies a TSC-format merkle proof against the node's chain, using the SV
+    /// `verifymerkleproof` RPC.
+    ///
+    /// Returns `Ok(false)` if the proof is well formed but doesn't prove inclusion in the
+    /// target block.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof to verify, e.g. as returned by
+    ///   [`get_merkle_proof2`](Self::get_merkle_proof2)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidProof`] if the node rejects the proof as malformed, and
+    /// [`Error::NotFound`] if the node doesn't know the target block.
+    pub async fn verify_merkle_proof(&self, proof: &TscMerkleProof) -> Result<bool> {
+        self.rpc.verify_merkle_proof(proof).await
+    }
+
+    /// Returns the current state of the node's mempool.
+    ///
+    /// SV-specific counters such as the journal size are `None` on nodes that don't report
+    /// them.
+    pub async fn get_mempool_info(&self) -> Result<MempoolInfo> {
+        self.rpc.get_mempool_info().await
+    }
+
+    /// Returns the ids of all transactions in the node's mempool.
+    ///
+    /// The list can be very large on mainnet; the ids are parsed directly from the response
+    /// without intermediate copies.
+    pub async fn get_raw_mempool(&self) -> Result<Vec<TxHash>> {
+        self.rpc.get_raw_mempool().await
+    }
+
+    /// Returns all transactions in the node's mempool with their fees, sizes and dependencies.
+    ///
+    /// Fees are converted exactly to satoshis. The response can be hundreds of megabytes on
+    /// mainnet; it is parsed straight into the map, so peak memory use is about the size of
+    /// the response plus the resulting map.
+    pub async fn get_raw_mempool_verbose(&self) -> Result<HashMap<TxHash, MempoolEntry>> {
+        self.rpc.get_raw_mempool_verbose().await
+    }
+
+    /// Returns the mempool details of a single unconfirmed transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The id of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the transaction is not in the mempool.
+    pub async fn get_mempool_entry(&self, txid: &TxHash) -> Result<MempoolEntry> {
+        self.rpc.get_mempool_entry(txid).await
+    }
+
+    /// Returns the ids of the in-mempool ancestors of a mempool transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The id of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the transaction is not in the mempool.
+    pub async fn get_mempool_ancestors(&self, txid: &TxHash) -> Result<Vec<TxHash>> {
+        self.rpc.get_mempool_ancestors(txid).await
+    }
+
+    /// Returns the in-mempool ancestors of a mempool transaction with their mempool details.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The id of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the transaction is not in the mempool.
+    pub async fn get_mempool_ancestors_verbose(
+        &self,
+        txid: &TxHash,
+    ) -> Result<HashMap<TxHash, MempoolEntry>> {
+        self.rpc.get_mempool_ancestors_verbose(txid).await
+    }
+
+    /// Returns the ids of the in-mempool descendants of a mempool transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The id of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the transaction is not in the mempool.
+    pub async fn get_mempool_descendants(&self, txid: &TxHash) -> Result<Vec<TxHash>> {
+        self.rpc.get_mempool_descendants(txid).await
+    }
+
+    /// Returns the in-mempool descendants of a mempool transaction with their mempool details.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The id of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the transaction is not in the mempool.
+    pub async fn get_mempool_descendants_verbose(
+        &self,
+        txid: &TxHash,
+    ) -> Result<HashMap<TxHash, MempoolEntry>> {
+        self.rpc.get_mempool_descendants_verbose(txid).await
+    }
+
+    /// Writes the node's mempool to disk, so it is reloaded when the node restarts.
+    ///
+    /// # Errors
+    ///
+    /// If the node can't write the mempool, for example because a previous dump is still in
+    /// progress, its error is returned as [`Error::Rpc`] with the node's code and message, and
+    /// the call can be retried later.
+    pub async fn save_mempool(&self) -> Result<()> {
+        self.rpc.save_mempool().await
+    }
+
+    /// Returns mining-related state of the node, such as the size of the current block
+    /// template and the estimated network hash rate.
+    pub async fn get_mining_info(&self) -> Result<MiningInfo> {
+        self.rpc.get_mining_info().await
+    }
+
+    /// Returns a block to mine, using the SV `getminingcandidate` RPC.
+    ///
+    /// The merkle branch is in internal byte order, ready for computing the merkle root once
+    /// the coinbase transaction is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `provide_coinbase_tx` - Include a coinbase transaction paying to the node's wallet
+    pub async fn get_mining_candidate(&self, provide_coinbase_tx: bool) -> Result<MiningCandidate> {
+        self.rpc.get_mining_candidate(provide_coinbase_tx).await
+    }
+
+    /// Submits a solved mining candidate, using the SV `submitminingsolution` RPC.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The candidate id and the values that solve it
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BlockRejected`] with the node's reject reason (e.g. `high-hash` or
+    /// `bad-txnmrklroot`) if the block is rejected.
+    pub async fn submit_mining_solution(&self, solution: &MiningSolution) -> Result<()> {
+        self.rpc.submit_mining_solution(solution).await
+    }
+
+    /// Returns a block template for external block assembly, using `getblocktemplate`.
+    ///
+    /// Templates on a busy network can contain a very large number of transactions; the
+    /// transaction hex is deserialized straight from the response without intermediate copies.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Capabilities and rules supported by the caller, or `None` for the defaults
+    pub async fn get_block_template(
+        &self,
+        request: Option<&BlockTemplateRequest>,
+    ) -> Result<BlockTemplate> {
+        self.rpc.get_block_template(request).await
+    }
+
+    /// Submits a serialized block to the node, using `submitblock`.
+    ///
+    /// Returns `None` if the block was accepted, or the node's result string otherwise:
+    /// `duplicate` if the block is already known, `inconclusive` if it was stored but not
+    /// connected to the best chain, or the reason it was rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hex` - The serialized block as hex
+    pub async fn submit_block(&self, block_hex: &str) -> Result<Option<String>> {
+        self.rpc.submit_block(block_hex).await
+    }
+
+    /// Submits a serialized block to the node, hex-encoding it first.
+    ///
+    /// See [`submit_block`](Self::submit_block) for the result.
+    pub async fn submit_block_bytes(&self, block: &[u8]) -> Result<Option<String>> {
+        self.rpc.submit_block(&hex::encode(block)).await
+    }
+
+    /// Mines blocks immediately to an address, using `generatetoaddress`.
+    ///
+    /// This is only useful on regtest, where it lets tests create blocks on demand.
+    ///
+    /// # Arguments
+    ///
+    /// * `nblocks` - Number of blocks to mine
+    /// * `address` - Address that receives the coinbase outputs
+    /// * `max_tries` - Maximum number of nonces to try, or `None` for the node's default
+    ///
+    /// # Errors
+    ///
+    /// Returns an RPC error if the address is invalid.
+    pub async fn generate_to_address(
+        &self,
+        nblocks: u32,
+        address: &str,
+        max_tries: Option<u32>,
+    ) -> Result<Vec<BlockHash>> {
+        self.rpc
+            .generate_to_address(nblocks, address, max_tries)
+            .await
+    }
+
+    /// Mines blocks immediately to the node's wallet, using `generate`.
+    ///
+    /// The coinbase outputs pay to a key of the wallet chosen with
+    /// [`with_wallet`](Self::with_wallet), if any. This is only useful on regtest. Newer nodes
+    /// have removed `generate`; use [`generate_to_address`](Self::generate_to_address) with
+    /// them.
+    ///
+    /// # Arguments
+    ///
+    /// * `nblocks` - Number of blocks to mine
+    /// * `max_tries` - Maximum number of nonces to try, or `None` for the node's default
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedMethod`] if the node doesn't provide `generate`, either
+    /// because it has no wallet or because the method was removed.
+    pub async fn generate(&self, nblocks: u32, max_tries: Option<u32>) -> Result<Vec<BlockHash>> {
+        self.rpc.generate(nblocks, max_tries).await
+    }
+
+    /// Estimates the fee rate needed for a transaction to confirm, using `estimatefee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `nblocks` - Number of blocks to confirm within, or `None` for the node's default
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoFeeEstimate`] if the node doesn't have enough data for an estimate.
+    pub async fn estimate_fee(&self, nblocks: Option<u32>) -> Result<FeeRate> {
+        self.rpc.estimate_fee(nblocks).await
+    }
+
+    /// Returns information about each peer connected to the node, using `getpeerinfo`.
+    pub async fn get_peer_info(&self) -> Result<Vec<PeerInfo>> {
+        self.rpc.get_peer_info().await
+    }
+
+    /// Returns the number of connections the node has to other nodes, using
+    /// `getconnectioncount`.
+    ///
+    /// This is a cheap call, suitable for frequent liveness checks.
+    pub async fn get_connection_count(&self) -> Result<u64> {
+        self.rpc.get_connection_count().await
+    }
+
+    /// Returns the node's network traffic totals and upload target state, using `getnettotals`.
+    pub async fn get_net_totals(&self) -> Result<NetTotals> {
+        self.rpc.get_net_totals().await
+    }
+
+    /// Adds or removes a peer in the node's list of added nodes, or tries a single connection
+    /// to it, using `addnode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Address of the peer, e.g. `203.0.113.7:8333`
+    /// * `command` - The action to take
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NodeAlreadyAdded`] when adding a peer that is already in the list, and
+    /// [`Error::NodeNotAdded`] when removing one that isn't. Callers that want idempotent
+    /// behaviour can treat these as success.
+    pub async fn add_node(&self, addr: &str, command: AddNodeCommand) -> Result<()> {
+        self.rpc.add_node(addr, command).await
+    }
+
+    /// Disconnects a peer, using `disconnectnode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The peer's address or node id
+    ///
+    /// # Errors
+    ///
+    /// Returns the node's RPC error (code -29) if the peer isn't connected.
+    pub async fn disconnect_node(&self, target: DisconnectTarget) -> Result<()> {
+        self.rpc.disconnect_node(&target).await
+    }
+
+    /// Returns the peers added with [`add_node`](Self::add_node) and whether they are
+    /// connected, using `getaddednodeinfo`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - Only return information about this added peer, or `None` for all of them
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NodeNotAdded`] if `node` is not in the list of added peers.
+    pub async fn get_added_node_info(&self, node: Option<&str>) -> Result<Vec<AddedNodeInfo>> {
+        self.rpc.get_added_node_info(node).await
+    }
+
+    /// Bans or unbans an IP address or subnet, using `setban`.
+    ///
+    /// # Arguments
+    ///
+    /// * `subnet` - An IP address, or a subnet with a prefix length or netmask (e.g.
+    ///   `192.0.2.0/24`)
+    /// * `command` - Whether to add or remove the ban
+    /// * `ban_time_secs` - How long to ban for in seconds, or `None` for the node's default
+    /// * `absolute` - Whether `ban_time_secs` is an absolute unix time rather than a duration
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] without contacting the node if `subnet` isn't a valid address
+    /// or subnet. The node's RPC error is returned as is when banning a subnet that is already
+    /// banned, or unbanning one that isn't.
+    pub async fn set_ban(
+        &self,
+        subnet: &str,
+        command: BanCommand,
+        ban_time_secs: Option<u64>,
+        absolute: bool,
+    ) -> Result<()> {
+        self.rpc
+            .set_ban(subnet, command, ban_time_secs, absolute)
+            .await
+    }
+
+    /// Returns the addresses and subnets the node has banned, using `listbanned`.
+    pub async fn list_banned(&self) -> Result<Vec<BannedEntry>> {
+        self.rpc.list_banned().await
+    }
+
+    /// Removes all bans, using `clearbanned`.
+    pub async fn clear_banned(&self) -> Result<()> {
+        self.rpc.clear_banned().await
+    }
+
+    /// Enables or disables all P2P network activity, using `setnetworkactive`.
+    ///
+    /// Disabling the network disconnects all peers but leaves the node running, which is
+    /// useful during maintenance. Returns the networking state reported by the node after the
+    /// change.
+    ///
+    /// # Arguments
+    ///
+    /// * `active` - Whether networking should be enabled
+    pub async fn set_network_active(&self, active: bool) -> Result<bool> {
+        self.rpc.set_network_active(active).await
+    }
+
+    /// Asks the node to send a ping to all of its peers, using `ping`.
+    ///
+    /// The results are reported asynchronously in the ping times of
+    /// [`get_peer_info`](Self::get_peer_info).
+    pub async fn ping_peers(&self) -> Result<()> {
+        self.rpc.ping().await
+    }
+
+    /// Measures the round trip time of a lightweight RPC call (`getbestblockhash`) to the node.
+    ///
+    /// This includes the HTTP request and the node's handling of it, which makes it a direct
+    /// health metric for the node.
+    pub async fn measure_rpc_latency(&self) -> Result<Duration> {
+        self.rpc.measure_latency().await
+    }
+
+    /// Returns the node's memory usage, using `getmemoryinfo`.
+    pub async fn get_memory_info(&self) -> Result<MemoryInfo> {
+        self.rpc.get_memory_info().await
+    }
+
+    /// Returns the memory allocator's statistics, using `getmemoryinfo` in `mallocinfo` mode.
+    ///
+    /// The result is the XML produced by glibc's `malloc_info`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an RPC error if the node wasn't built with glibc.
+    pub async fn get_malloc_info(&self) -> Result<String> {
+        self.rpc.get_malloc_info().await
+    }
+
+    /// Returns a summary of the node's state, using the legacy `getinfo` RPC.
+    ///
+    /// Prefer [`get_blockchain_info`](Self::get_blockchain_info) and the other specific
+    /// calls in new code. Fields the node doesn't report are `None`.
+    pub async fn get_info(&self) -> Result<NodeInfo> {
+        self.rpc.get_info().await
+    }
+
+    /// Verifies the scripts of transaction inputs, using the SV `verifyscript` RPC.
+    ///
+    /// The results are in the same order as `checks`.
+    ///
+    /// # Arguments
+    ///
+    /// * `checks` - The inputs to check, optionally with the outputs they spend
+    /// * `stop_on_first_invalid` - Skip the remaining checks after the first failure
+    /// * `timeout_secs` - Time limit for all checks together, or `None` for the node's default
+    pub async fn verify_script(
+        &self,
+        checks: &[ScriptCheck],
+        stop_on_first_invalid: bool,
+        timeout_secs: Option<u64>,
+    ) -> Result<Vec<ScriptCheckResult>> {
+        self.rpc
+            .verify_script(checks, stop_on_first_invalid, timeout_secs)
+            .await
+    }
+
+    /// Returns the node's safe mode state and the forks or reorg that caused it, using the SV
+    /// `getsafemodeinfo` RPC.
+    pub async fn get_safe_mode_info(&self) -> Result<SafeModeInfo> {
+        self.rpc.get_safe_mode_info().await
+    }
+
+    /// Stops a block, and the fork it is part of, from putting the node into safe mode, using
+    /// `ignoresafemodeforblock`.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - Hash of a block on the fork to ignore
+    pub async fn ignore_safe_mode_for_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.rpc.ignore_safe_mode_for_block(block_hash).await
+    }
+
+    /// Reverses [`ignore_safe_mode_for_block`](Self::ignore_safe_mode_for_block), using
+    /// `reconsidersafemodeforblock`.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - Hash of a block on the fork to consider again
+    pub async fn reconsider_safe_mode_for_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.rpc.reconsider_safe_mode_for_block(block_hash).await
+    }
+
+    /// Freezes outputs by adding them to the node's policy blacklist, using the SV
+    /// `addToPolicyBlacklist` RPC.
+    ///
+    /// Transactions spending a policy-frozen output are not relayed or mined by this node. Only
+    /// the outputs themselves are sent; the consensus enforcement details of each
+    /// [`FrozenTxo`] are ignored.
+    ///
+    /// Returns the outputs the node did not process.
+    pub async fn add_to_policy_blacklist(&self, txos: &[FrozenTxo]) -> Result<BlacklistResult> {
+        self.rpc.add_to_policy_blacklist(txos).await
+    }
+
+    /// Freezes outputs at the consensus level, using the SV `addToConsensusBlacklist` RPC.
+    ///
+    /// Blocks spending a consensus-frozen output within its `enforce_at_height` intervals are
+    /// invalid. Consensus-frozen outputs are also added to the policy blacklist.
+    ///
+    /// Returns the outputs the node did not process.
+    pub async fn add_to_consensus_blacklist(&self, txos: &[FrozenTxo]) -> Result<BlacklistResult> {
+        self.rpc.add_to_consensus_blacklist(txos).await
+    }
+
+    /// Unfreezes outputs by removing them from the policy blacklist, using the SV
+    /// `removeFromPolicyBlacklist` RPC.
+    ///
+    /// Outputs that are also on the consensus blacklist are not removed and are reported as not
+    /// processed.
+    pub async fn remove_from_policy_blacklist(
+        &self,
+        txos: &[FrozenTxo],
+    ) -> Result<BlacklistResult> {
+        self.rpc.remove_from_policy_blacklist(txos).await
+    }
+
+    /// Returns all frozen outputs and the blacklists they are on, using the SV `queryBlacklist`
+    /// RPC.
+    pub async fn query_blacklist(&self) -> Result<Vec<BlacklistEntry>> {
+        self.rpc.query_blacklist().await
+    }
+
+    /// Removes entries from the blacklists, using the SV `clearBlacklists` RPC, and returns the
+    /// number of entries removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `remove_all_entries` - Remove every entry rather than only expired consensus entries
+    /// * `expiration_height_delta` - Only remove consensus entries whose enforcement ended at
+    ///   least this many blocks ago, or `None` for the node's default
+    pub async fn clear_blacklists(
+        &self,
+        remove_all_entries: bool,
+        expiration_height_delta: Option<u64>,
+    ) -> Result<u64> {
+        self.rpc
+            .clear_blacklists(remove_all_entries, expiration_height_delta)
+            .await
+    }
+
+    /// Validates an address, using `validateaddress`.
+    ///
+    /// An invalid address is not an error: it is returned with
+    /// [`is_valid`](AddressValidation::is_valid) set to `false`.
+    pub async fn validate_address(&self, address: &str) -> Result<AddressValidation> {
+        self.rpc.validate_address(address).await
+    }
+
+    /// Verifies a message signed with the private key of an address, using `verifymessage`.
+    ///
+    /// A signature that isn't valid base64 returns `Ok(false)`, the same as a signature that
+    /// doesn't match, rather than an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The P2PKH address whose key signed the message
+    /// * `signature_base64` - The signature, as produced by `signmessage`
+    /// * `message` - The message that was signed
+    ///
+    /// # Errors
+    ///
+    /// Returns an RPC error if the address is invalid or doesn't refer to a key.
+    pub async fn verify_message(
+        &self,
+        address: &str,
+        signature_base64: &str,
+        message: &str,
+    ) -> Result<bool> {
+        self.rpc
+            .verify_message(address, signature_base64, message)
+            .await
+    }
+
+    /// Creates a P2SH multisig address, using `createmultisig`.
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - Number of signatures required to spend
+    /// * `keys` - The public keys, as hex
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] without contacting the node if `required` is zero or more than
+    /// the number of keys. The node's RPC error is returned as is for invalid public keys.
+    pub async fn create_multisig(&self, required: u32, keys: &[String]) -> Result<MultisigInfo> {
+        self.rpc.create_multisig(required, keys).await
+    }
+
+    /// Sets the fee rate the node's wallet pays for the transactions it creates, using
+    /// `settxfee`.
+    ///
+    /// The rate is sent as an exact decimal amount per kilobyte. Returns `true` if the node
+    /// accepted it.
+    pub async fn set_tx_fee(&self, fee: FeeRate) -> Result<bool> {
+        self.rpc.set_tx_fee(fee).await
+    }
+
+    /// Returns the names of the RPC methods the node provides, sorted.
+    ///
+    /// The list is read from the node's `help` output on first use and cached for the lifetime
+    /// of the client and its clones.
+    pub async fn list_methods(&self) -> Result<Vec<String>> {
+        Ok(self.rpc.list_methods().await?.to_vec())
+    }
+
+    /// Returns whether the node provides an RPC method, e.g. to choose between
+    /// [`generate`](Self::generate) and [`generate_to_address`](Self::generate_to_address).
+    ///
+    /// Uses the same cached method list as [`list_methods`](Self::list_methods).
+    pub async fn supports_method(&self, method: &str) -> Result<bool> {
+        self.rpc.supports_method(method).await
+    }
+
+    /// Returns a new address from the node's wallet, using `getnewaddress`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label (account) to file the address under, or `None` for the default
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn get_new_address(&self, label: Option<&str>) -> Result<String> {
+        self.rpc.get_new_address(label).await
+    }
+
+    /// Returns the balance of the node's wallet in satoshis, using `getbalance`.
+    ///
+    /// The node's decimal amount is converted exactly, without going through `f64`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_conf` - Only count outputs with at least this many confirmations, or `None` for
+    ///   the node's default
+    /// * `include_watch_only` - Also count watch-only addresses
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn get_balance(
+        &self,
+        min_conf: Option<u32>,
+        include_watch_only: bool,
+    ) -> Result<u64> {
+        self.rpc.get_balance(min_conf, include_watch_only).await
+    }
+
+    /// Returns the unconfirmed balance of the node's wallet in satoshis, using
+    /// `getunconfirmedbalance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn get_unconfirmed_balance(&self) -> Result<u64> {
+        self.rpc.get_unconfirmed_balance().await
+    }
+
+    /// Returns the unspent outputs of the node's wallet, using `listunspent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_conf` - Minimum number of confirmations, or `None` for the node's default of 1
+    /// * `max_conf` - Maximum number of confirmations, or `None` for no limit
+    /// * `addresses` - Only return outputs paying to these addresses, or `None` for all
+    /// * `include_unsafe` - Also return unconfirmed outputs from other wallets, which may be
+    ///   replaced or never confirm
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn list_unspent(
+        &self,
+        min_conf: Option<u32>,
+        max_conf: Option<u32>,
+        addresses: Option<&[String]>,
+        include_unsafe: bool,
+    ) -> Result<Vec<Unspent>> {
+        self.rpc
+            .list_unspent(min_conf, max_conf, addresses, include_unsafe)
+            .await
+    }
+
+    /// Sends coins from the node's wallet to an address, using `sendtoaddress`.
+    ///
+    /// Returns the id of the wallet transaction, which is already in the node's mempool.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Address to pay
+    /// * `amount_satoshis` - Amount to send, in satoshis
+    /// * `comment` - Comment stored with the transaction in the wallet (not sent on the network)
+    /// * `subtract_fee` - Deduct the fee from the amount sent rather than adding it on top
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet. Wallet failures keep
+    /// the node's error code as [`Error::Rpc`], e.g. `-6` for insufficient funds.
+    pub async fn send_to_address(
+        &self,
+        address: &str,
+        amount_satoshis: u64,
+        comment: Option<&str>,
+        subtract_fee: bool,
+    ) -> Result<TxHash> {
+        self.rpc
+            .send_to_address(address, amount_satoshis, comment, subtract_fee)
+            .await
+    }
+
+    /// Sends coins from the node's wallet to several addresses in one transaction, using
+    /// `sendmany`.
+    ///
+    /// Returns the id of the wallet transaction. The node's legacy "fromaccount" parameter is
+    /// always the default account.
+    ///
+    /// # Arguments
+    ///
+    /// * `outputs` - Amount to pay each address, in satoshis
+    /// * `min_conf` - Only spend outputs with at least this many confirmations, or `None` for
+    ///   the node's default of 1
+    /// * `comment` - Comment stored with the transaction in the wallet (not sent on the network)
+    /// * `subtract_fee_from` - Addresses whose outputs pay the fee, split equally between them,
+    ///   instead of it being added on top. Each must also be a key of `outputs`; that output
+    ///   then receives its amount minus its share of the fee.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `subtract_fee_from` names an address that is not in
+    /// `outputs` (the node would silently ignore it), and [`Error::WalletUnavailable`] if the
+    /// node has no wallet. Wallet failures keep the node's error code as [`Error::Rpc`], e.g.
+    /// `-6` for insufficient funds.
+    pub async fn send_many(
+        &self,
+        outputs: &HashMap<String, u64>,
+        min_conf: Option<u32>,
+        comment: Option<&str>,
+        subtract_fee_from: Option<&[String]>,
+    ) -> Result<TxHash> {
+        self.rpc
+            .send_many(outputs, min_conf, comment, subtract_fee_from)
+            .await
+    }
+
+    /// Returns the most recent transactions of the node's wallet, oldest first, using
+    /// `listtransactions`.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of entries to return, or `None` for the node's default of 10
+    /// * `skip` - Number of most recent entries to skip, for paging back through history
+    /// * `include_watch_only` - Also include transactions of watch-only addresses
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn list_transactions(
+        &self,
+        count: Option<u32>,
+        skip: Option<u32>,
+        include_watch_only: bool,
+    ) -> Result<Vec<WalletTxInfo>> {
+        self.rpc
+            .list_transactions(count, skip, include_watch_only)
+            .await
+    }
+
+    /// Returns a transaction known to the node's wallet, using `gettransaction`.
+    ///
+    /// Unlike [`get_raw_transaction`](Self::get_raw_transaction) this doesn't need
+    /// `-txindex`, but only finds transactions that involve the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The transaction to look up
+    /// * `include_watch_only` - Also count watch-only addresses in the amounts and details
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the transaction is not in the wallet, and
+    /// [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn get_wallet_transaction(
+        &self,
+        txid: &TxHash,
+        include_watch_only: bool,
+    ) -> Result<WalletTransaction> {
+        self.rpc
+            .get_wallet_transaction(txid, include_watch_only)
+            .await
+    }
+
+    /// Adds a private key to the node's wallet, using `importprivkey`.
+    ///
+    /// With `rescan` the node scans the whole chain for the key's transactions before it
+    /// answers, which can take a long time. The client does not apply a request timeout of its
+    /// own, so the HTTP request stays open for as long as the node needs.
+    ///
+    /// # Arguments
+    ///
+    /// * `wif` - The private key in wallet import format
+    /// * `label` - Label for the key's address, or `None` for no label
+    /// * `rescan` - Rescan the chain for transactions involving the key
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `wif` is not shaped like a WIF key (no request is made), and
+    /// [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn import_priv_key(
+        &self,
+        wif: &str,
+        label: Option<&str>,
+        rescan: bool,
+    ) -> Result<()> {
+        self.rpc.import_priv_key(wif, label, rescan).await
+    }
+
+    /// Adds a watch-only address or script to the node's wallet, using `importaddress`.
+    ///
+    /// The wallet then tracks payments to it without being able to spend them. With `rescan`
+    /// the node scans the whole chain for matching transactions before it answers, which can
+    /// take a long time. The client does not apply a request timeout of its own, so the HTTP
+    /// request stays open for as long as the node needs.
+    ///
+    /// # Arguments
+    ///
+    /// * `address_or_script` - An address, or an output script as hex
+    /// * `label` - Label for the address, or `None` for no label
+    /// * `rescan` - Rescan the chain for transactions involving the address
+    /// * `p2sh` - Treat `address_or_script` as a redeem script and also watch its P2SH address
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet. If `p2sh` is set for an
+    /// address rather than a script, the node's RPC error explaining this is returned.
+    pub async fn import_address(
+        &self,
+        address_or_script: &str,
+        label: Option<&str>,
+        rescan: bool,
+        p2sh: bool,
+    ) -> Result<()> {
+        self.rpc
+            .import_address(address_or_script, label, rescan, p2sh)
+            .await
+    }
+
+    /// Returns the private key of a wallet address in wallet import format, using
+    /// `dumpprivkey`.
+    ///
+    /// The crate does no logging, so the key is only ever held in the returned string.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - An address whose key is in the wallet
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletLocked`] if the wallet is encrypted and locked (unlock it with
+    /// `walletpassphrase` first), and [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn dump_priv_key(&self, address: &str) -> Result<String> {
+        self.rpc.dump_priv_key(address).await
+    }
+
+    /// Copies the node's wallet file, using `backupwallet`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_destination_path` - Destination file or directory on the node's filesystem,
+    ///   not the caller's
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FileAccessDenied`] if the node couldn't write the copy, and
+    /// [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn backup_wallet(&self, node_destination_path: &str) -> Result<()> {
+        self.rpc.backup_wallet(node_destination_path).await
+    }
+
+    /// Writes all keys in the node's wallet to a human-readable file, using `dumpwallet`.
+    ///
+    /// Returns the full path of the file written on the node's filesystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_filename` - File to write on the node's filesystem, not the caller's. Relative
+    ///   paths are resolved against the node's working directory, and the node refuses to
+    ///   overwrite an existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FileAccessDenied`] if the node couldn't open the file for writing,
+    /// [`Error::WalletLocked`] if the wallet is encrypted and locked, and
+    /// [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn dump_wallet(&self, node_filename: &str) -> Result<String> {
+        self.rpc.dump_wallet(node_filename).await
+    }
+
+    /// Returns the state of the node's wallet, using `getwalletinfo`.
+    ///
+    /// [`WalletInfo::unlocked_until`] tells whether a passphrase is needed: it is `None` for
+    /// an unencrypted wallet and `Some(0)` for a locked one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn get_wallet_info(&self) -> Result<WalletInfo> {
+        self.rpc.get_wallet_info().await
+    }
+
+    /// Encrypts the node's wallet with a passphrase, using `encryptwallet`.
+    ///
+    /// The node shuts down once the wallet is encrypted and must be restarted. Returns the
+    /// node's message; if the node closes the connection before finishing its response, a
+    /// standard message is returned instead, as for [`NodeManagement::stop`].
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The new passphrase, which is never included in errors or debug output
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] for an empty passphrase, and [`Error::WalletUnavailable`] if
+    /// the node has no wallet. An already encrypted wallet fails with [`Error::Rpc`] code
+    /// `-15`.
+    pub async fn encrypt_wallet(&self, passphrase: &str) -> Result<String> {
+        self.rpc.encrypt_wallet(passphrase).await
+    }
+
+    /// Unlocks the node's encrypted wallet for a while, using `walletpassphrase`.
+    ///
+    /// # Arguments
+    ///
+    /// * `passphrase` - The wallet passphrase, which is never included in errors or debug
+    ///   output
+    /// * `timeout_secs` - Seconds after which the node locks the wallet again
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] for an empty passphrase, and [`Error::WalletUnavailable`] if
+    /// the node has no wallet. A wrong passphrase fails with [`Error::Rpc`] code `-14`.
+    pub async fn wallet_passphrase(&self, passphrase: &str, timeout_secs: u64) -> Result<()> {
+        self.rpc.wallet_passphrase(passphrase, timeout_secs).await
+    }
+
+    /// Changes the passphrase of the node's encrypted wallet, using `walletpassphrasechange`.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_passphrase` - The current passphrase
+    /// * `new_passphrase` - The new passphrase
+    ///
+    /// Neither passphrase is ever included in errors or debug output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] for an empty passphrase, and [`Error::WalletUnavailable`] if
+    /// the node has no wallet. A wrong passphrase fails with [`Error::Rpc`] code `-14`.
+    pub async fn wallet_passphrase_change(
+        &self,
+        old_passphrase: &str,
+        new_passphrase: &str,
+    ) -> Result<()> {
+        self.rpc
+            .wallet_passphrase_change(old_passphrase, new_passphrase)
+            .await
+    }
+
+    /// Locks the node's encrypted wallet, using `walletlock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn wallet_lock(&self) -> Result<()> {
+        self.rpc.wallet_lock().await
+    }
+
+    /// Signs a message with the private key of a wallet address, using `signmessage`.
+    ///
+    /// Returns the signature as base64, which can be checked with
+    /// [`verify_message`](Self::verify_message).
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - A P2PKH address whose key is in the wallet
+    /// * `message` - The message to sign
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::KeyNotInWallet`] if the wallet has no key for the address or the
+    /// address is not a key address, [`Error::WalletLocked`] if the wallet is encrypted and
+    /// locked, and [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn sign_message(&self, address: &str, message: &str) -> Result<String> {
+        self.rpc.sign_message(address, message).await
+    }
+
+    /// Returns the amounts received by each address of the node's wallet, using
+    /// `listreceivedbyaddress`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_conf` - Only count transactions with at least this many confirmations, or `None`
+    ///   for the node's default of 1
+    /// * `include_empty` - Also list addresses that haven't received anything
+    /// * `include_watch_only` - Also list watch-only addresses
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn list_received_by_address(
+        &self,
+        min_conf: Option<u32>,
+        include_empty: bool,
+        include_watch_only: bool,
+    ) -> Result<Vec<ReceivedByAddress>> {
+        self.rpc
+            .list_received_by_address(min_conf, include_empty, include_watch_only)
+            .await
+    }
+
+    /// Returns the total received by a wallet address in satoshis, using
+    /// `getreceivedbyaddress`.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - An address of the wallet
+    /// * `min_conf` - Only count transactions with at least this many confirmations, or `None`
+    ///   for the node's default of 1
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn get_received_by_address(
+        &self,
+        address: &str,
+        min_conf: Option<u32>,
+    ) -> Result<u64> {
+        self.rpc.get_received_by_address(address, min_conf).await
+    }
+
+    /// Adds a multisig address to the node's wallet, using `addmultisigaddress`.
+    ///
+    /// Returns the new P2SH address. The wallet watches it, and can sign for it with the keys
+    /// it holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - Number of signatures needed to spend
+    /// * `keys` - Public keys as hex, or addresses of the wallet
+    /// * `label` - Label for the address, or `None` for no label
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `required` is 0 or more than the number of keys (no request
+    /// is made), and [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn add_multisig_address(
+        &self,
+        required: u32,
+        keys: &[String],
+        label: Option<&str>,
+    ) -> Result<String> {
+        self.rpc.add_multisig_address(required, keys, label).await
+    }
+
+    /// Fills the node wallet's pool of pre-generated keys, using `keypoolrefill`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_size` - Number of keys the pool should hold, or `None` for the node's setting
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletLocked`] if the wallet is encrypted and locked, and
+    /// [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn key_pool_refill(&self, new_size: Option<u32>) -> Result<()> {
+        self.rpc.key_pool_refill(new_size).await
+    }
+
+    /// Marks a stuck wallet transaction as abandoned, using `abandontransaction`.
+    ///
+    /// The wallet can then spend the transaction's inputs again. Only transactions that are
+    /// neither confirmed nor in the mempool can be abandoned, so a transaction still in the
+    /// mempool must be evicted first (e.g. by restarting the node with `-persistmempool=0`).
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The wallet transaction to abandon
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the transaction is not in the wallet, and
+    /// [`Error::WalletUnavailable`] if the node has no wallet. A transaction that is confirmed
+    /// or in the mempool is refused with the node's [`Error::Rpc`] (code `-5`, "Transaction not
+    /// eligible for abandonment"). The node uses `-5` for unknown transactions too, so they are
+    /// told apart by the node's message.
+    pub async fn abandon_transaction(&self, txid: &TxHash) -> Result<()> {
+        self.rpc.abandon_transaction(txid).await
+    }
+
+    /// Locks or unlocks outputs of the node's wallet, using `lockunspent`.
+    ///
+    /// The wallet doesn't spend locked outputs, and [`list_unspent`](Self::list_unspent)
+    /// leaves them out. Locks are held in memory and cleared when the node restarts.
+    ///
+    /// # Arguments
+    ///
+    /// * `unlock` - Unlock the outputs rather than lock them
+    /// * `outpoints` - The outputs as (transaction id, output index) pairs. An empty slice
+    ///   changes nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet. An unknown or already
+    /// spent output fails with [`Error::Rpc`] code `-8`.
+    pub async fn lock_unspent(&self, unlock: bool, outpoints: &[(TxHash, u32)]) -> Result<bool> {
+        self.rpc.lock_unspent(unlock, outpoints).await
+    }
+
+    /// Returns the outputs of the node's wallet locked with
+    /// [`lock_unspent`](Self::lock_unspent), using `listlockunspent`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn list_lock_unspent(&self) -> Result<Vec<(TxHash, u32)>> {
+        self.rpc.list_lock_unspent().await
+    }
+
+    /// Returns groups of the node wallet's addresses whose common ownership has been made
+    /// public by spending from them together or through change, using `listaddressgroupings`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet.
+    pub async fn list_address_groupings(&self) -> Result<Vec<Vec<AddressGroupEntry>>> {
+        self.rpc.list_address_groupings().await
+    }
+
+    /// Returns a client that sends wallet calls to one of the node's wallets.
+    ///
+    /// A node with several wallets loaded needs wallet calls to be posted to
+    /// `/wallet/<name>`; calls to the chain and network keep using the root URL. The name is
+    /// percent-encoded, so it may contain spaces or slashes. The new client is a cheap clone
+    /// that shares this client's connections.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of a loaded wallet, as returned by [`list_wallets`](Self::list_wallets)
+    pub fn with_wallet(&self, name: &str) -> SvNodeClient {
+        SvNodeClient {
+            rpc: self.rpc.with_wallet(name),
+            rest: self.rest.clone(),
+        }
+    }
+
+    /// Returns the names of the wallets loaded by the node, using `listwallets`.
+    ///
+    /// The default wallet is named `""`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WalletUnavailable`] if the node has no wallet support.
+    pub async fn list_wallets(&self) -> Result<Vec<String>> {
+        self.rpc.list_wallets().await
+    }
+
+    /// Returns up to `count` consecutive block headers starting at `start`, in one request to
+    /// the REST interface (`/rest/headers`).
+    ///
+    /// The headers follow the best chain, so fewer than `count` are returned if its tip is
+    /// reached first. This is much faster than fetching headers one by one when syncing.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Hash of the first header to return
+    /// * `count` - Number of headers, between 1 and 2000 (the node's limit)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `count` is out of range (no request is made),
+    /// [`Error::NotFound`] if the node doesn't know `start`, and [`Error::BitcoinSv`] if the
+    /// response is not a whole number of headers.
+    pub async fn get_block_headers(
+        &self,
+        start: &BlockHash,
+        count: u32,
+    ) -> Result<Vec<BlockHeader>> {
+        self.rest.get_headers(start, count).await
+    }
+
+    /// Returns a transaction fetched from the REST interface (`/rest/tx`), in the format set
+    /// by [`with_rest_format`](Self::with_rest_format).
+    ///
+    /// In the default binary format this avoids the hex encoding of
+    /// [`get_raw_transaction`](Self::get_raw_transaction), which matters for large
+    /// transactions. As with that method, without `-txindex` the node only has mempool
+    /// transactions and transactions with unspent outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `txid` - The transaction to fetch
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the node doesn't have the transaction.
+    pub async fn get_transaction_rest(&self, txid: &TxHash) -> Result<Tx> {
+        self.rest.get_transaction(txid).await
+    }
+
+    /// Returns the current state of the node's blockchain from the REST interface
+    /// (`/rest/chaininfo.json`).
+    ///
+    /// The result is the same as [`get_blockchain_info`](Self::get_blockchain_info), but the
+    /// REST interface needs no RPC credentials, which suits public-facing monitoring.
+    pub async fn get_chain_info_rest(&self) -> Result<BlockchainInfo> {
+        self.rest.get_chain_info().await
+    }
+
+    /// Returns whether transaction outputs are unspent, with their values and scripts, from the
+    /// REST interface (`/rest/getutxos`).
+    ///
+    /// The node handles at most 15 outputs per request; longer lists are split into several
+    /// requests and the results joined in the order given.
+    ///
+    /// # Arguments
+    ///
+    /// * `outpoints` - The outputs as (transaction id, output index) pairs
+    /// * `check_mempool` - Also consider the mempool, so outputs created by mempool
+    ///   transactions are found and outputs spent by them are reported as spent
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `outpoints` is empty.
+    pub async fn get_utxos_rest(
+        &self,
+        outpoints: &[(TxHash, u32)],
+        check_mempool: bool,
+    ) -> Result<UtxoSetResult> {
+        self.rest.get_utxos(outpoints, check_mempool).await
+    }
+
+    /// Returns the state of the node's mempool from the REST interface
+    /// (`/rest/mempool/info.json`).
+    ///
+    /// The result is the same as [`get_mempool_info`](Self::get_mempool_info), without
+    /// needing RPC credentials.
+    pub async fn get_mempool_info_rest(&self) -> Result<MempoolInfo> {
+        self.rest.get_mempool_info().await
+    }
+
+    /// Returns all transactions in the node's mempool with their mempool details, from the
+    /// REST interface (`/rest/mempool/contents.json`).
+    ///
+    /// The result is the same as [`get_raw_mempool_verbose`](Self::get_raw_mempool_verbose).
+    /// On a busy node the response can be hundreds of megabytes. It is decoded as it arrives
+    /// rather than buffered whole, but the returned map holds every entry, so expect memory use
+    /// in proportion to the mempool size. Prefer [`get_raw_mempool`](Self::get_raw_mempool)
+    /// when only the ids are needed.
+    pub async fn get_mempool_contents_rest(&self) -> Result<HashMap<TxHash, MempoolEntry>> {
+        self.rest.get_mempool_contents().await
+    }
+
+    /// Returns block metadata and the ids of the block's transactions from the REST interface
+    /// (`/rest/block/notxdetails`).
+    ///
+    /// The result is the same as [`get_block_info`](Self::get_block_info), without needing
+    /// RPC credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the block
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if the node doesn't know the block, and [`Error::Other`]
+    /// quoting the start of the response if it isn't valid block JSON.
+    pub async fn get_block_info_rest(&self, block_hash: &BlockHash) -> Result<BlockInfo> {
+        self.rest.get_block_notxdetails(block_hash).await
+    }
+
+    /// Returns the current state of the node's blockchain.
+    ///
+    /// Includes the chain name, block and header counts, best block hash, difficulty,
+    /// median time, verification progress, pruning status and chain work.
+    pub async fn get_blockchain_info(&self) -> Result<BlockchainInfo> {
+        self.rpc.get_blockchain_info().await
+    }
+
+    /// Returns all known tips in the block tree.
+    ///
+    /// This includes the active tip of the main chain as well as stale forks, branches for
+    /// which only headers are known and branches containing invalid blocks.
+    pub async fn get_chain_tips(&self) -> Result<Vec<ChainTip>> {
+        self.rpc.get_chain_tips().await
+    }
+
+    /// Returns transaction throughput statistics for a window of blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `nblocks` - Size of the window in blocks (defaults to one month of blocks)
+    /// * `block_hash` - Hash of the block that ends the window (defaults to the tip)
+    pub async fn get_chain_tx_stats(
+        &self,
+        nblocks: Option<u32>,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<ChainTxStats> {
+        self.rpc.get_chain_tx_stats(nblocks, block_hash).await
+    }
+
+    /// Returns the block header for the specified block hash along with its height,
+    /// confirmations, median time, chain work and neighbouring block hashes.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the block
+    pub async fn get_block_header_info(&self, block_hash: &BlockHash) -> Result<BlockHeaderInfo> {
+        self.rpc.get_block_header_info(block_hash).await
+    }
+
+    /// Returns block metadata and the ids of the block's transactions.
+    ///
+    /// Uses the JSON-RPC interface, so the transactions themselves are not transferred. Use
+    /// [`get_block`](NodeClient::get_block) to retrieve the complete block.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the block
+    pub async fn get_block_info(&self, block_hash: &BlockHash) -> Result<BlockInfo> {
+        self.rpc.get_block_info(block_hash).await
+    }
+
+    /// Returns a block with its transactions decoded by the node.
+    ///
+    /// Inputs and outputs are decoded so scripts don't have to be parsed client-side. Output
+    /// values are converted exactly to satoshis. Decoded blocks are several times larger than
+    /// the binary block, so prefer [`get_block`](NodeClient::get_block) for large blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the block
+    pub async fn get_block_decoded(&self, block_hash: &BlockHash) -> Result<DecodedBlock> {
+        self.rpc.get_block_decoded(block_hash).await
+    }
+
+    /// Returns the block at the specified height in the longest blockchain.
+    ///
+    /// Uses the SV node's `getblockbyheight` RPC to avoid a separate `getblockhash` round
+    /// trip. Nodes that don't provide `getblockbyheight` are handled by looking up the hash
+    /// first and then fetching the block (over REST for verbosity 0).
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The height of the block
+    /// * `verbosity` - 0 for the parsed [`Block`], 1 for [`BlockInfo`] or 2 for
+    ///   [`DecodedBlock`], returned as the corresponding [`BlockData`] variant
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BlockHeightOutOfRange`] if the height is beyond the current tip and
+    /// [`Error::Config`] for an unsupported verbosity.
+    pub async fn get_block_by_height(&self, height: u64, verbosity: u8) -> Result<BlockData> {
+        match self.rpc.get_block_by_height(height, verbosity).await {
+            Err(Error::Rpc {
+                code: RPC_METHOD_NOT_FOUND,
+                ..
+            }) => {
+                let hash = self.rpc.get_block_hash(height).await?;
+                match verbosity {
+                    0 => self.rest.get_block(&hash).await.map(BlockData::Raw),
+                    1 => self.rpc.get_block_info(&hash).await.map(BlockData::Info),
+                    2 => self
+                        .rpc
+                        .get_block_decoded(&hash)
+                        .await
+                        .map(BlockData::Decoded),
+                    _ => Err(invalid_block_verbosity(verbosity)),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Marks a block as invalid, as if it violated a consensus rule.
+    ///
+    /// The node disconnects the block and its descendants and reorganises onto the best
+    /// remaining chain. This is mainly useful for reorg testing on regtest, and can be undone
+    /// with `reconsiderblock`.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the block to invalidate
+    pub async fn invalidate_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.rpc.invalidate_block(block_hash).await
+    }
+
+    /// Removes the invalidity status of a block and its descendants.
+    ///
+    /// This undoes [`invalidate_block`](Self::invalidate_block). The node reconsiders the
+    /// blocks and reorganises onto them if they form the best chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the block to reconsider
+    pub async fn reconsider_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.rpc.reconsider_block(block_hash).await
+    }
+
+    /// Waits for the node to connect a new block and returns the new tip.
+    ///
+    /// This is a long poll: the node holds the request open until a new block arrives or the
+    /// timeout elapses, in which case the current tip is returned. The client does not apply a
+    /// request timeout of its own, so the HTTP request stays open for as long as the node needs.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout_ms` - Maximum time to wait in milliseconds, or `None` (or 0) to wait
+    ///   indefinitely
+    pub async fn wait_for_new_block(&self, timeout_ms: Option<u64>) -> Result<TipInfo> {
+        self.rpc.wait_for_new_block(timeout_ms).await
+    }
+
+    /// Waits for the node's best chain to reach the given height and returns the tip.
+    ///
+    /// Like [`wait_for_new_block`](Self::wait_for_new_block) this is a long poll that is not
+    /// subject to a client-side request timeout. If the timeout elapses before the height is
+    /// reached the node returns the current tip, so check `tip.height >= height` to tell the
+    /// two outcomes apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The height to wait for
+    /// * `timeout_ms` - Maximum time to wait in milliseconds, or `None` (or 0) to wait
+    ///   indefinitely
+    pub async fn wait_for_block_height(
+        &self,
+        height: u64,
+        timeout_ms: Option<u64>,
+    ) -> Result<TipInfo> {
+        self.rpc.wait_for_block_height(height, timeout_ms).await
+    }
+
+    /// Waits for the block with the given hash to become the node's tip and returns the tip.
+    ///
+    /// Useful to await acceptance of a block submitted through another channel. Like
+    /// [`wait_for_new_block`](Self::wait_for_new_block) this is a long poll that is not subject
+    /// to a client-side request timeout. If the timeout elapses first the node returns the
+    /// current tip, so compare `tip.hash` with `block_hash` to tell the two outcomes apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the block to wait for
+    /// * `timeout_ms` - Maximum time to wait in milliseconds, or `None` (or 0) to wait
+    ///   indefinitely
+    pub async fn wait_for_block(
+        &self,
+        block_hash: &BlockHash,
+        timeout_ms: Option<u64>,
+    ) -> Result<TipInfo> {
+        self.rpc.wait_for_block(block_hash, timeout_ms).await
+    }
+
+    /// Returns the estimated network hash rate in hashes per second.
+    ///
+    /// # Arguments
+    ///
+    /// * `nblocks` - Number of blocks to average over, or -1 for the blocks since the last
+    ///   difficulty change (node default 120)
+    /// * `height` - Height of the block to estimate at, for historical hash rates (defaults to
+    ///   the tip)
+    pub async fn get_network_hash_ps(
+        &self,
+        nblocks: Option<i32>,
+        height: Option<u64>,
+    ) -> Result<f64> {
+        self.rpc.get_network_hash_ps(nblocks, height).await
+    }
+
+    /// Returns per-block statistics computed by the node.
+    ///
+    /// This avoids downloading and parsing the whole block locally.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The block, identified by hash or height
+    /// * `stats` - Names of the statistics to compute (e.g. `["totalfee", "txs"]`), or `None`
+    ///   for all of them. Statistics that were not requested are `None` in the result.
+    pub async fn get_block_stats(
+        &self,
+        target: BlockStatsTarget,
+        stats: Option<&[&str]>,
+    ) -> Result<BlockStats> {
+        self.rpc.get_block_stats(target, stats).await
+    }
+
+    /// Verifies the node's blockchain database and returns whether it is valid.
+    ///
+    /// Verification of many blocks at a high check level can take minutes. The client does not
+    /// apply a request timeout, so this call waits until the node has finished verifying; use
+    /// `tokio::time::timeout` if you need to bound it. Abandoning the call does not stop the
+    /// verification on the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `check_level` - How thorough the verification is, 0 to 4 (node default 3)
+    /// * `num_blocks` - Number of blocks to check, 0 for all (node default 6)
+    pub async fn verify_chain(
+        &self,
+        check_level: Option<u32>,
+        num_blocks: Option<u32>,
+    ) -> Result<bool> {
+        self.rpc.verify_chain(check_level, num_blocks).await
+    }
+}
+
+#[async_trait]
+impl NodeClient for SvNodeClient {
+    async fn get_best_block_hash(&self) -> Result<BlockHash> {
+        self.rpc.get_best_block_hash().await
+    }
+
+    async fn get_block_count(&self) -> Result<u64> {
+        self.rpc.get_block_count().await
+    }
+
+    async fn get_block_hash(&self, height: u64) -> Result<BlockHash> {
+        self.rpc.get_block_hash(height).await
+    }
+
+    async fn get_difficulty(&self) -> Result<f64> {
+        self.rpc.get_difficulty().await
+    }
+
+    async fn get_block_header(&self, block_hash: &BlockHash) -> Result<BlockHeader> {
+        self.rpc.get_block_header(block_hash).await
+    }
+
+    async fn get_block(&self, block_hash: &BlockHash) -> Result<Block> {
+        self.rest.get_block(block_hash).await
+    }
+}
+
+#[async_trait]
+impl NodeManagement for SvNodeClient {
+    async fn check_journal(&self) -> Result<JournalCheckResult> {
+        self.rpc.check_journal().await
+    }
+
+    async fn rebuild_journal(&self) -> Result<()> {
+        self.rpc.rebuild_journal().await
+    }
+
+    async fn stop(&self) -> Result<String> {
+        self.rpc.stop().await
+    }
+
+    async fn uptime(&self) -> Result<Duration> {
+        self.rpc.uptime().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// The mainnet genesis block.
+    const GENESIS_BLOCK_HEX: &str = "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c0101000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+
+    /// Hash of the mainnet genesis block.
+    const GENESIS_HASH: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+    /// Mounts a JSON-RPC response for requests matching `request` on the mock server.
+    async fn mount_rpc(
+        server: &MockServer,
+        request: serde_json::Value,
+        response: serde_json::Value,
+    ) {
+        Mock::given(method("POST"))
+            .and(body_partial_json(request))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn test_client_creation() {
+        let client = SvNodeClient::new(
+            "http://localhost:8332",
+            Some("user".to_string()),
+            Some("password".to_string()),
+        );
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_client_creation_no_auth() {
+        let client = SvNodeClient::new("http://localhost:8332", None, None);
+        assert!(client.is_ok());
+    }
+
+    /// Parses the coinbase transaction of the genesis block, which follows the 80-byte header
+    /// and the one-byte transaction count.
+    fn genesis_coinbase() -> Tx {
+        let bytes = hex::decode(&GENESIS_BLOCK_HEX[162..]).unwrap();
+        Tx::from_binary(&mut &bytes[..]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_transaction() {
+        let server = MockServer::start().await;
+        let tx = genesis_coinbase();
+        mount_rpc(
+            &server,
+            json!({"method": "sendrawtransaction", "params": [&GENESIS_BLOCK_HEX[162..], false, false]}),
+            json!({"result": tx.hash().to_string(), "error": null, "id": "bitcoinsv-rpc"}),
+        )
+        .await;
+
+        let client = SvNodeClient::new(&server.uri(), None, None).unwrap();
+        assert_eq!(client.broadcast_transaction(&tx).await.unwrap(), tx.hash());
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_transaction_txid_mismatch() {
+        let server = MockServer::start().await;
+        let tx = genesis_coinbase();
+        let other = "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4";
+        mount_rpc(
+            &server,
+            json!({"method": "sendrawtransaction"}),
+            json!({"result": other, "error": null, "id": "bitcoinsv-rpc"}),
+        )
+        .await;
+
+        let client = SvNodeClient::new(&server.uri(), None, None).unwrap();
+        match client.broadcast_transaction(&tx).await {
+            Err(Error::TxidMismatch { expected, actual }) => {
+                assert_eq!(expected, tx.hash());
+                assert_eq!(actual.to_string(), other);
+            }
+            other => panic!("expected TxidMismatch, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_node_management_check_journal() {
+        let server = MockServer::start().await;
+        mount_rpc(
+            &server,
+            json!({"method": "checkjournal"}),
+            json!({"result": {"ok": true, "errors": ""}, "error": null, "id": "bitcoinsv-rpc"}),
+        )
+        .await;
+
+        let client = SvNodeClient::new(&server.uri(), None, None).unwrap();
+        let management: &dyn NodeManagement = &client;
+        assert!(management.check_journal().await.unwrap().ok);
+    }
+
+    #[tokio::test]
+    async fn test_uptime() {
+        let server = MockServer::start().await;
+        mount_rpc(
+            &server,
+            json!({"method": "uptime", "params": []}),
+            json!({"result": 86461, "error": null, "id": "bitcoinsv-rpc"}),
+        )
+        .await;
+
+        let client = SvNodeClient::new(&server.uri(), None, None).unwrap();
+        assert_eq!(client.uptime().await.unwrap(), Duration::from_secs(86461));
+    }
+
+    #[tokio::test]
+    async fn test_get_block_by_height() {
+        let server = MockServer::start().await;
+        mount_rpc(
+            &server,
+            json!({"method": "getblockbyheight", "params": [0, 0]}),
+            json!({"result": GENESIS_BLOCK_HEX, "error": null, "id": "bitcoinsv-rpc"}),
+        )
+        .await;
+
+        let client = SvNodeClient::new(&server.uri(), None, None).unwrap();
+        match client.get_block_by_height(0, 0).await.unwrap() {
+            BlockData::Raw(block) => {
+                assert_eq!(block.header().unwrap().hash().to_string(), GENESIS_HASH);
+            }
+            _ => panic!("expected a raw block"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_block_by_height_falls_back_on_older_nodes() {
+        let server = MockServer::start().await;
+        mount_rpc(
+            &server,
+            json!({"method": "getblockbyheight"}),
+            json!({
+                "result": null,
+                "error": {"code": -32601, "message": "Method not found"},
+                "id": "bitcoinsv-rpc"
+            }),
+        )
+        .await;
+        mount_rpc(
+            &server,
+            json!({"method": "getblockhash", "params": [0]}),
+            json!({"result": GENESIS_HASH, "error": null, "id": "bitcoinsv-rpc"}),
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/rest/block/{}.bin", GENESIS_HASH)))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_bytes(hex::decode(GENESIS_BLOCK_HEX).unwrap()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = SvNodeClient::new(&server.uri(), None, None).unwrap();
+        match client.get_block_by_height(0, 0).await.unwrap() {
+            BlockData::Raw(block) => {
+                assert_eq!(block.header().unwrap().hash().to_string(), GENESIS_HASH);
+            }
+            _ => panic!("expected a raw block"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_info_rpc_and_rest_match() {
+        let info = json!({
+            "chain": "regtest",
+            "blocks": 101,
+            "headers": 101,
+            "bestblockhash": GENESIS_HASH,
+            "difficulty": 4.656542373906925e-10,
+            "mediantime": 1700000000,
+            "verificationprogress": 1,
+            "chainwork": "00000000000000000000000000000000000000000000000000000000000000cc",
+            "pruned": false,
+            "softforks": [],
+            "bip9_softforks": {}
+        });
+        let server = MockServer::start().await;
+        mount_rpc(
+            &server,
+            json!({"method": "getblockchaininfo"}),
+            json!({"result": info, "error": null, "id": "bitcoinsv-rpc"}),
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/chaininfo.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&info))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = SvNodeClient::new(&server.uri(), None, None).unwrap();
+        let rpc = client.get_blockchain_info().await.unwrap();
+        let rest = client.get_chain_info_rest().await.unwrap();
+        assert_eq!(rpc, rest);
+        assert_eq!(rest.blocks, 101);
+    }
+
+    #[tokio::test]
+    async fn test_get_block_by_height_invalid_verbosity() {
+        let client = SvNodeClient::new("http://localhost:8332", None, None).unwrap();
+        assert!(matches!(
+            client.get_block_by_height(0, 3).await,
+            Err(Error::Config(_))
+        ));
     }
 }
@@ -1,5 +1,6 @@
 //! Error types for the Fandango library.
 
+use bitcoinsv::bitcoin::TxHash;
 use thiserror::Error;
 
 /// A specialized Result type for Fandango operations.
@@ -28,6 +29,59 @@ pub enum Error {
     #[error("Bitcoin SV parsing failed: {0}")]
     BitcoinSv(String),
 
+    /// Requested block height is beyond the tip of the node's best chain
+    #[error("Block height {0} is out of range")]
+    BlockHeightOutOfRange(u64),
+
+    /// The requested item (e.g. a transaction or block) is not known to the node
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    /// The node is already in the node's list of added peers
+    #[error("Node already added: {0}")]
+    NodeAlreadyAdded(String),
+
+    /// The node is not in the node's list of added peers
+    #[error("Node not added: {0}")]
+    NodeNotAdded(String),
+
+    /// The node rejected a block or mining solution, with its reject reason (e.g. `high-hash`)
+    #[error("Block rejected: {0}")]
+    BlockRejected(String),
+
+    /// The node doesn't have enough data to estimate a fee
+    #[error("No fee estimate available")]
+    NoFeeEstimate,
+
+    /// The node has no wallet, because it was built or started without wallet support
+    #[error("The node's wallet is not available")]
+    WalletUnavailable,
+
+    /// The wallet is encrypted and must be unlocked with `walletpassphrase` first
+    #[error("The node's wallet is locked")]
+    WalletLocked,
+
+    /// The wallet holds no private key for the address, or the address can't sign messages
+    #[error("No wallet key for address: {0}")]
+    KeyNotInWallet(String),
+
+    /// The node couldn't write a file at the requested path on its own filesystem, with the
+    /// node's message
+    #[error("Node file access denied: {0}")]
+    FileAccessDenied(String),
+
+    /// The node doesn't provide an RPC method, with a note on what to use instead
+    #[error("Unsupported method: {0}")]
+    UnsupportedMethod(String),
+
+    /// A merkle proof failed verification
+    #[error("Invalid proof: {0}")]
+    InvalidProof(String),
+
+    /// The node reported a different txid for a broadcast transaction than its own hash
+    #[error("Transaction id mismatch: expected {expected}, node returned {actual}")]
+    TxidMismatch { expected: TxHash, actual: TxHash },
+
     /// Invalid URL provided
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
@@ -32,10 +32,13 @@
 //! }
 //! ```
 
+mod amount;
 mod client;
 mod error;
 mod rest;
 mod rpc;
+pub mod types;
 
-pub use client::{NodeClient, SvNodeClient};
+pub use client::{NodeClient, NodeManagement, SvNodeClient};
 pub use error::{Error, Result};
+pub use rest::RestFormat;
@@ -1,14 +1,54 @@
 //! REST API client implementation for Bitcoin SV nodes.
 
 use crate::error::{Error, Result};
-use bitcoinsv::bitcoin::{Block, BlockHash};
-use reqwest::Client;
+use crate::types::de::HexHashMap;
+use crate::types::{BlockInfo, BlockchainInfo, MempoolEntry, MempoolInfo, UtxoSetResult};
+use bitcoinsv::bitcoin::{Block, BlockHash, BlockHeader, Encodable, Tx, TxHash};
+use reqwest::{Client, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::io::{self, BufReader, Read};
+use tokio::sync::mpsc;
+
+/// The most headers the node returns from a single `/rest/headers` request
+pub(crate) const MAX_REST_HEADERS: u32 = 2000;
+
+/// The most outputs the node looks up in a single `/rest/getutxos` request
+pub(crate) const MAX_REST_UTXOS: usize = 15;
+
+/// Size of a serialized block header in bytes
+const HEADER_SIZE: usize = 80;
+
+/// How much of a malformed response body is included in the error, in bytes
+const BODY_SNIPPET_LEN: usize = 200;
+
+/// Encoding requested for blocks, transactions and headers from the REST interface
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RestFormat {
+    /// Raw bytes (`.bin`), the most compact encoding
+    #[default]
+    Binary,
+    /// Hex text (`.hex`), twice the size but passed intact by proxies that mangle binary
+    /// responses
+    Hex,
+}
+
+impl RestFormat {
+    /// The file extension that selects this format in a REST path
+    fn extension(self) -> &'static str {
+        match self {
+            RestFormat::Binary => "bin",
+            RestFormat::Hex => "hex",
+        }
+    }
+}
 
 /// Client for REST API communication with Bitcoin SV node
 #[derive(Clone)]
 pub(crate) struct RestClient {
     base_url: String,
     client: Client,
+    format: RestFormat,
 }
 
 impl RestClient {
@@ -26,14 +66,28 @@ impl RestClient {
         Ok(Self {
             base_url,
             client: Client::new(),
+            format: RestFormat::default(),
         })
     }
 
-    /// Gets a block in binary format from the REST API
+    /// Returns a client that requests blocks, transactions and headers in the given format
+    pub fn with_format(&self, format: RestFormat) -> Self {
+        Self {
+            format,
+            ..self.clone()
+        }
+    }
+
+    /// Gets a block from the REST API
     ///
-    /// Uses the endpoint: GET /rest/block/<BLOCK-HASH>.bin
+    /// Uses the endpoint: GET /rest/block/<BLOCK-HASH>.<bin|hex>
     pub async fn get_block(&self, block_hash: &BlockHash) -> Result<Block> {
-        let url = format!("{}/rest/block/{}.bin", self.base_url, block_hash);
+        let url = format!(
+            "{}/rest/block/{}.{}",
+            self.base_url,
+            block_hash,
+            self.format.extension()
+        );
 
         let response = self.client.get(&url).send().await?;
 
@@ -44,17 +98,732 @@ impl RestClient {
             )));
         }
 
-        let bytes = response.bytes().await?;
+        let bytes = self.read_data(response).await?;
 
         // Parse binary data using bitcoinsv crate
-        // reqwest::Bytes is compatible with bitcoinsv's expected Bytes type
-        Block::new(bytes).map_err(|e| Error::BitcoinSv(format!("Failed to parse block: {}", e)))
+        Block::new(bytes.into())
+            .map_err(|e| Error::BitcoinSv(format!("Failed to parse block: {}", e)))
+    }
+
+    /// Gets block metadata and the ids of its transactions from the REST API
+    ///
+    /// Uses the endpoint: GET /rest/block/notxdetails/<BLOCK-HASH>.json
+    ///
+    /// The response has the same form as `getblock` with verbosity 1.
+    pub async fn get_block_notxdetails(&self, block_hash: &BlockHash) -> Result<BlockInfo> {
+        let path = format!("/rest/block/notxdetails/{}.json", block_hash);
+        let bytes = self
+            .fetch(&path, || format!("block {}", block_hash))
+            .await?
+            .bytes()
+            .await?;
+        parse_json(&bytes, "block info")
+    }
+
+    /// Gets consecutive block headers from the REST API
+    ///
+    /// Uses the endpoint: GET /rest/headers/<COUNT>/<BLOCK-HASH>.<bin|hex>
+    ///
+    /// The headers start at `start` and follow the best chain, so fewer than `count` are
+    /// returned if the tip is reached first.
+    pub async fn get_headers(&self, start: &BlockHash, count: u32) -> Result<Vec<BlockHeader>> {
+        if count == 0 || count > MAX_REST_HEADERS {
+            return Err(Error::Config(format!(
+                "header count must be between 1 and {}, got {}",
+                MAX_REST_HEADERS, count
+            )));
+        }
+        let path = format!(
+            "/rest/headers/{}/{}.{}",
+            count,
+            start,
+            self.format.extension()
+        );
+        let response = self.fetch(&path, || format!("block {}", start)).await?;
+        let bytes = self.read_data(response).await?;
+        if bytes.len() % HEADER_SIZE != 0 {
+            return Err(Error::BitcoinSv(format!(
+                "Header data is {} bytes, not a multiple of {}",
+                bytes.len(),
+                HEADER_SIZE
+            )));
+        }
+
+        bytes
+            .chunks_exact(HEADER_SIZE)
+            .map(|mut chunk| {
+                BlockHeader::from_binary(&mut chunk)
+                    .map_err(|e| Error::BitcoinSv(format!("Failed to parse block header: {}", e)))
+            })
+            .collect()
+    }
+
+    /// Gets a transaction from the REST API
+    ///
+    /// Uses the endpoint: GET /rest/tx/<TX-HASH>.<bin|hex>
+    pub async fn get_transaction(&self, txid: &TxHash) -> Result<Tx> {
+        let path = format!("/rest/tx/{}.{}", txid, self.format.extension());
+        let response = self
+            .fetch(&path, || format!("transaction {}", txid))
+            .await?;
+        let bytes = self.read_data(response).await?;
+
+        Tx::from_binary(&mut &bytes[..])
+            .map_err(|e| Error::BitcoinSv(format!("Failed to parse transaction: {}", e)))
+    }
+
+    /// Gets the current state of the blockchain from the REST API
+    ///
+    /// Uses the endpoint: GET /rest/chaininfo.json
+    ///
+    /// The REST interface needs no credentials, and the response has the same form as
+    /// `getblockchaininfo`.
+    pub async fn get_chain_info(&self) -> Result<BlockchainInfo> {
+        let bytes = self
+            .fetch("/rest/chaininfo.json", || "chain info".to_string())
+            .await?
+            .bytes()
+            .await?;
+        parse_json(&bytes, "chain info")
+    }
+
+    /// Gets the status of transaction outputs from the REST API
+    ///
+    /// Uses the endpoint: GET /rest/getutxos[/checkmempool]/<TXID>-<N>/...json
+    ///
+    /// The node looks up at most [`MAX_REST_UTXOS`] outputs per request, so longer lists are
+    /// split into several requests whose results are joined in order. If a block arrives
+    /// between requests, the chain height and tip are those of the last request.
+    pub async fn get_utxos(
+        &self,
+        outpoints: &[(TxHash, u32)],
+        check_mempool: bool,
+    ) -> Result<UtxoSetResult> {
+        let mut chunks = outpoints.chunks(MAX_REST_UTXOS);
+        let first = chunks
+            .next()
+            .ok_or_else(|| Error::Config("no outputs to look up".to_string()))?;
+        let mut result = self.get_utxos_chunk(first, check_mempool).await?;
+        for chunk in chunks {
+            let next = self.get_utxos_chunk(chunk, check_mempool).await?;
+            result.chain_height = next.chain_height;
+            result.chain_tip_hash = next.chain_tip_hash;
+            result.bitmap.extend(next.bitmap);
+            result.utxos.extend(next.utxos);
+        }
+        Ok(result)
+    }
+
+    /// Gets the status of at most [`MAX_REST_UTXOS`] outputs in a single request
+    async fn get_utxos_chunk(
+        &self,
+        outpoints: &[(TxHash, u32)],
+        check_mempool: bool,
+    ) -> Result<UtxoSetResult> {
+        let mut path = String::from("/rest/getutxos");
+        if check_mempool {
+            path.push_str("/checkmempool");
+        }
+        for (txid, n) in outpoints {
+            path.push_str(&format!("/{}-{}", txid, n));
+        }
+        path.push_str(".json");
+
+        let bytes = self
+            .fetch(&path, || "outputs".to_string())
+            .await?
+            .bytes()
+            .await?;
+        let result: UtxoSetResult = parse_json(&bytes, "outputs")?;
+        if result.bitmap.len() != outpoints.len() {
+            return Err(Error::Other(format!(
+                "getutxos returned {} results for {} outputs",
+                result.bitmap.len(),
+                outpoints.len()
+            )));
+        }
+        Ok(result)
+    }
+
+    /// Gets the state of the mempool from the REST API
+    ///
+    /// Uses the endpoint: GET /rest/mempool/info.json
+    pub async fn get_mempool_info(&self) -> Result<MempoolInfo> {
+        let bytes = self
+            .fetch("/rest/mempool/info.json", || "mempool info".to_string())
+            .await?
+            .bytes()
+            .await?;
+        parse_json(&bytes, "mempool info")
+    }
+
+    /// Gets all transactions in the mempool with their mempool details from the REST API
+    ///
+    /// Uses the endpoint: GET /rest/mempool/contents.json
+    ///
+    /// The response is decoded as it arrives rather than buffered whole, but the resulting map
+    /// still holds an entry for every mempool transaction.
+    pub async fn get_mempool_contents(&self) -> Result<HashMap<TxHash, MempoolEntry>> {
+        let response = self
+            .fetch("/rest/mempool/contents.json", || {
+                "mempool contents".to_string()
+            })
+            .await?;
+        let HexHashMap(entries) = decode_streaming(response).await?;
+        Ok(entries)
+    }
+
+    /// Reads a block, transaction or header response body as raw bytes, decoding it if the
+    /// client requests hex
+    async fn read_data(&self, response: Response) -> Result<Vec<u8>> {
+        let body = response.bytes().await?;
+        match self.format {
+            RestFormat::Binary => Ok(body.into()),
+            // The node ends hex responses with a newline
+            RestFormat::Hex => Ok(hex::decode(body.trim_ascii())?),
+        }
+    }
+
+    /// Requests a REST endpoint, returning the response once a success status is received
+    ///
+    /// A 404 response is reported as [`Error::NotFound`] for the item described by `item`.
+    async fn fetch(&self, path: &str, item: impl FnOnce() -> String) -> Result<Response> {
+        let url = format!("{}{}", self.base_url, path);
+
+        let response = self.client.get(&url).send().await?;
+
+        match response.status() {
+            status if status.is_success() => Ok(response),
+            StatusCode::NOT_FOUND => Err(Error::NotFound(item())),
+            status => Err(Error::Other(format!("REST API returned error: {}", status))),
+        }
+    }
+}
+
+/// Deserializes a JSON response body, quoting the start of the body if it is malformed.
+fn parse_json<T: DeserializeOwned>(bytes: &[u8], what: &str) -> Result<T> {
+    serde_json::from_slice(bytes).map_err(|e| {
+        let snippet = String::from_utf8_lossy(&bytes[..bytes.len().min(BODY_SNIPPET_LEN)]);
+        Error::Other(format!(
+            "Failed to parse {}: {}; response began: {:?}",
+            what, e, snippet
+        ))
+    })
+}
+
+/// Number of response chunks that may wait for the decoder in [`decode_streaming`]
+const STREAM_CHUNKS: usize = 16;
+
+/// Deserializes a JSON response body as it arrives, without buffering the whole body.
+///
+/// `serde_json` reads synchronously, so the decoder runs on a blocking thread and is fed the
+/// body's chunks through a bounded channel.
+async fn decode_streaming<T: DeserializeOwned + Send + 'static>(
+    mut response: Response,
+) -> Result<T> {
+    let (sender, receiver) = mpsc::channel(STREAM_CHUNKS);
+    let decoder = tokio::task::spawn_blocking(move || {
+        let reader = BufReader::new(ChunkReader {
+            receiver,
+            chunk: None,
+            pos: 0,
+        });
+        serde_json::from_reader::<_, T>(reader)
+    });
+
+    while let Some(chunk) = response.chunk().await? {
+        // The decoder hangs up early if the body is malformed
+        if sender.send(chunk).await.is_err() {
+            break;
+        }
+    }
+    drop(sender);
+
+    decoder
+        .await
+        .map_err(|e| Error::Other(format!("Response decoder failed: {}", e)))?
+        .map_err(Error::from)
+}
+
+/// A blocking reader over response chunks received from a channel.
+struct ChunkReader<B> {
+    receiver: mpsc::Receiver<B>,
+    chunk: Option<B>,
+    pos: usize,
+}
+
+impl<B: AsRef<[u8]>> Read for ChunkReader<B> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if let Some(chunk) = &self.chunk {
+                let remaining = &chunk.as_ref()[self.pos..];
+                if !remaining.is_empty() {
+                    let n = remaining.len().min(buf.len());
+                    buf[..n].copy_from_slice(&remaining[..n]);
+                    self.pos += n;
+                    return Ok(n);
+                }
+            }
+            match self.receiver.blocking_recv() {
+                Some(chunk) => {
+                    self.chunk = Some(chunk);
+                    self.pos = 0;
+                }
+                // The sender is dropped once the whole body has been sent
+                None => return Ok(0),
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hex::FromHex;
+    use wiremock::matchers::{method, path, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// The headers of mainnet blocks 0 and 1.
+    const GENESIS_HEADER_HEX: &str = "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c";
+    const BLOCK_1_HEADER_HEX: &str = "010000006fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000982051fd1e4ba744bbbe680e1fee14677ba1a3c3540bf7b1cdb606e857233e0e61bc6649ffff001d01e36299";
+    const GENESIS_HASH: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+    const BLOCK_1_HASH: &str = "00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048";
+    /// The coinbase transaction of the genesis block, its only transaction
+    const GENESIS_COINBASE_HEX: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+    const GENESIS_COINBASE_TXID: &str =
+        "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+
+    /// The serialized genesis block: its header, a transaction count of one and the coinbase.
+    fn genesis_block_hex() -> String {
+        format!("{}01{}", GENESIS_HEADER_HEX, GENESIS_COINBASE_HEX)
+    }
+
+    #[tokio::test]
+    async fn test_get_block_binary() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/rest/block/{}.bin", GENESIS_HASH)))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(hex::decode(genesis_block_hex()).unwrap()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        let block = client.get_block(&hash).await.unwrap();
+        assert_eq!(block.header().unwrap().hash(), hash);
+        assert_eq!(block.num_tx, 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_block_hex() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/rest/block/{}.hex", GENESIS_HASH)))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(format!("{}\n", genesis_block_hex())),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri())
+            .unwrap()
+            .with_format(RestFormat::Hex);
+        let hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        let block = client.get_block(&hash).await.unwrap();
+        assert_eq!(block.header().unwrap().hash(), hash);
+        assert_eq!(block.num_tx, 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_block_hex_invalid() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("not hex\n"))
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri())
+            .unwrap()
+            .with_format(RestFormat::Hex);
+        let hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        assert!(matches!(client.get_block(&hash).await, Err(Error::Hex(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_block_notxdetails() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/rest/block/notxdetails/{}.json",
+                GENESIS_HASH
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "hash": GENESIS_HASH,
+                "confirmations": 2,
+                "size": 285,
+                "height": 0,
+                "version": 1,
+                "versionHex": "00000001",
+                "merkleroot": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+                "num_tx": 1,
+                "tx": ["4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"],
+                "time": 1231006505,
+                "mediantime": 1231006505,
+                "nonce": 2083236893,
+                "bits": "1d00ffff",
+                "difficulty": 1,
+                "chainwork": "0000000000000000000000000000000000000000000000000000000100010001",
+                "nextblockhash": BLOCK_1_HASH
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        let info = client.get_block_notxdetails(&hash).await.unwrap();
+        assert_eq!(info.hash, hash);
+        assert_eq!(info.height, 0);
+        assert_eq!(info.tx.len(), 1);
+        assert_eq!(info.previous_block_hash, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_block_notxdetails_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("not found"))
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let hash = BlockHash::from_hex(BLOCK_1_HASH).unwrap();
+        let result = client.get_block_notxdetails(&hash).await;
+        assert!(matches!(result, Err(Error::NotFound(item)) if item.contains(BLOCK_1_HASH)));
+    }
+
+    #[tokio::test]
+    async fn test_get_block_notxdetails_malformed() {
+        // A proxy error page in place of the JSON, longer than the quoted snippet
+        let body = format!("<html>Bad Gateway{}</html>", " ".repeat(1000));
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        match client.get_block_notxdetails(&hash).await {
+            Err(Error::Other(message)) => {
+                assert!(message.contains("<html>Bad Gateway"), "{}", message);
+                assert!(!message.contains("</html>"), "{}", message);
+            }
+            other => panic!(
+                "expected a parse error, got {:?}",
+                other.map(|info| info.hash)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_info_malformed() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html>Bad Gateway</html>"))
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        match client.get_mempool_info().await {
+            Err(Error::Other(message)) => assert!(message.contains("Bad Gateway"), "{}", message),
+            other => panic!(
+                "expected a parse error, got {:?}",
+                other.map(|info| info.size)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_headers() {
+        let server = MockServer::start().await;
+        let body = hex::decode(format!("{}{}", GENESIS_HEADER_HEX, BLOCK_1_HEADER_HEX)).unwrap();
+        Mock::given(method("GET"))
+            .and(path(format!("/rest/headers/2/{}.bin", GENESIS_HASH)))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(body))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let start = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        let headers = client.get_headers(&start, 2).await.unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[0].hash(), start);
+        assert_eq!(headers[1].hash().to_string(), BLOCK_1_HASH);
+    }
+
+    #[tokio::test]
+    async fn test_get_headers_truncated() {
+        let server = MockServer::start().await;
+        let body = hex::decode(GENESIS_HEADER_HEX).unwrap();
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(&body[..79]))
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let start = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        let result = client.get_headers(&start, 1).await;
+        assert!(matches!(result, Err(Error::BitcoinSv(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction() {
+        let server = MockServer::start().await;
+        // The txid is in display byte order, as for block hashes
+        Mock::given(method("GET"))
+            .and(path(format!("/rest/tx/{}.bin", GENESIS_COINBASE_TXID)))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(hex::decode(GENESIS_COINBASE_HEX).unwrap()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let tx = client.get_transaction(&txid).await.unwrap();
+        assert_eq!(tx.hash(), txid);
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_hex() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/rest/tx/{}.hex", GENESIS_COINBASE_TXID)))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(format!("{}\n", GENESIS_COINBASE_HEX)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri())
+            .unwrap()
+            .with_format(RestFormat::Hex);
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let tx = client.get_transaction(&txid).await.unwrap();
+        assert_eq!(tx.hash(), txid);
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("not found"))
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let txid = TxHash::from_hex(BLOCK_1_HASH).unwrap();
+        let result = client.get_transaction(&txid).await;
+        assert!(matches!(result, Err(Error::NotFound(_))));
+    }
+
+    /// A `getutxos` response for `count` outputs, all unspent.
+    fn utxos_response(count: usize, height: u64) -> serde_json::Value {
+        let utxo = serde_json::json!({
+            "txvers": 1,
+            "height": 1,
+            "value": 50.00000001,
+            "scriptPubKey": {
+                "asm": "OP_DUP OP_HASH160 243f1394f44554f4ce3fd68649c19adc483ce924 OP_EQUALVERIFY OP_CHECKSIG",
+                "hex": "76a914243f1394f44554f4ce3fd68649c19adc483ce92488ac",
+                "reqSigs": 1,
+                "type": "pubkeyhash",
+                "addresses": ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"]
+            }
+        });
+        serde_json::json!({
+            "chainHeight": height,
+            "chaintipHash": GENESIS_HASH,
+            "bitmap": "1".repeat(count),
+            "utxos": vec![utxo; count]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_utxos() {
+        let server = MockServer::start().await;
+        let body = serde_json::json!({
+            "chainHeight": 101,
+            "chaintipHash": GENESIS_HASH,
+            "bitmap": "01",
+            "utxos": [{
+                "txvers": 1,
+                "height": 2147483647,
+                "value": 0.0005,
+                "scriptPubKey": {
+                    "asm": "OP_RETURN",
+                    "hex": "6a",
+                    "type": "nulldata"
+                }
+            }]
+        });
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/rest/getutxos/checkmempool/{}-0/{}-1.json",
+                BLOCK_1_HASH, BLOCK_1_HASH
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let txid = TxHash::from_hex(BLOCK_1_HASH).unwrap();
+        let result = client
+            .get_utxos(&[(txid, 0), (txid, 1)], true)
+            .await
+            .unwrap();
+        assert_eq!(result.chain_height, 101);
+        assert_eq!(result.bitmap, vec![false, true]);
+        assert_eq!(result.utxos.len(), 1);
+        assert_eq!(result.utxos[0].value, 50_000);
+    }
+
+    #[tokio::test]
+    async fn test_get_utxos_split_into_requests() {
+        let server = MockServer::start().await;
+        let txid = TxHash::from_hex(BLOCK_1_HASH).unwrap();
+        let outpoints: Vec<(TxHash, u32)> = (0..20).map(|n| (txid, n)).collect();
+
+        // The first request covers outputs 0 to 14, the second 15 to 19
+        Mock::given(method("GET"))
+            .and(path_regex(format!(
+                r"^/rest/getutxos/{}-0/.*-14\.json$",
+                BLOCK_1_HASH
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(utxos_response(15, 101)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(format!(
+                r"^/rest/getutxos/{}-15/.*-19\.json$",
+                BLOCK_1_HASH
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(utxos_response(5, 102)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let result = client.get_utxos(&outpoints, false).await.unwrap();
+        assert_eq!(result.bitmap.len(), 20);
+        assert_eq!(result.utxos.len(), 20);
+        assert_eq!(result.utxos[19].value, 5_000_000_001);
+        assert_eq!(result.chain_height, 102);
+    }
+
+    #[tokio::test]
+    async fn test_get_utxos_empty() {
+        // No request is made, so the client doesn't need a server
+        let client = RestClient::new("http://localhost:1").unwrap();
+        assert!(matches!(
+            client.get_utxos(&[], true).await,
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_info() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/mempool/info.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "size": 2,
+                "journalsize": 2,
+                "nonfinalsize": 0,
+                "bytes": 450,
+                "usage": 2176,
+                "maxmempool": 10000000000u64,
+                "mempoolminfee": 0.0
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let info = client.get_mempool_info().await.unwrap();
+        assert_eq!(info.size, 2);
+        assert_eq!(info.journal_size, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_contents() {
+        // Enough entries for the body to arrive in several chunks
+        let mut body = String::from("{");
+        for i in 0..2000u32 {
+            if i > 0 {
+                body.push(',');
+            }
+            body.push_str(&format!(
+                r#""{:064x}":{{"size":204,"fee":0.00000{:03},"modifiedfee":0.00001,"time":1700000000,"height":820000,"depends":[]}}"#,
+                i,
+                i % 1000
+            ));
+        }
+        body.push('}');
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rest/mempool/contents.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        let entries = client.get_mempool_contents().await.unwrap();
+        assert_eq!(entries.len(), 2000);
+        let txid = TxHash::from_hex(format!("{:064x}", 1234)).unwrap();
+        assert_eq!(entries[&txid].fee, 234);
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_contents_malformed() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw("{\"abc\": [", "application/json"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = RestClient::new(&server.uri()).unwrap();
+        assert!(matches!(
+            client.get_mempool_contents().await,
+            Err(Error::Json(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_headers_count_out_of_range() {
+        // No request is made, so the client doesn't need a server
+        let client = RestClient::new("http://localhost:1").unwrap();
+        let start = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        for count in [0, MAX_REST_HEADERS + 1] {
+            let result = client.get_headers(&start, count).await;
+            assert!(matches!(result, Err(Error::Config(_))), "{}", count);
+        }
+    }
 
     #[test]
     fn test_rest_client_creation() {
@@ -1,30 +1,103 @@
 //! JSON-RPC client implementation for Bitcoin SV nodes.
 
 use crate::error::{Error, Result};
-use bitcoinsv::bitcoin::{BlockHash, BlockHeader, Encodable};
+use crate::types::de::{self, HexHash, HexHashMap, LenientF64, SignedAmount};
+use crate::types::ser;
+use crate::types::{
+    AddNodeCommand, AddedNodeInfo, AddressGroupEntry, AddressValidation, BanCommand, BannedEntry,
+    BlacklistEntry, BlacklistQuery, BlacklistResult, BlockData, BlockHeaderInfo, BlockInfo,
+    BlockStats, BlockStatsTarget, BlockTemplate, BlockTemplateRequest, BlockchainInfo, ChainTip,
+    ChainTxStats, DecodedBlock, DecodedTransaction, DisconnectTarget, FeeRate, FrozenTxo,
+    FundRawOptions, FundRawResult, Funds, JournalCheckResult, MemoryInfo, MempoolEntry,
+    MempoolInfo, MerkleProof, MiningCandidate, MiningInfo, MiningSolution, MultisigInfo, NetTotals,
+    NodeInfo, PeerInfo, PolicyFund, RawTxSubmission, ReceivedByAddress, SafeModeInfo, ScriptCheck,
+    ScriptCheckResult, SendRawTransactionsResult, TargetType, TipInfo, TransactionInfo,
+    TscMerkleProof, TxInput, TxOutInfo, TxOutsEntry, TxOutsResult, Unspent, WalletInfo,
+    WalletTransaction, WalletTxInfo,
+};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bitcoinsv::bitcoin::{Block, BlockHash, BlockHeader, Encodable, Tx, TxHash};
 use hex::FromHex;
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::{Client, Response, Url};
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
 use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
+
+/// RPC error code for invalid, missing or out-of-range parameters
+pub(crate) const RPC_INVALID_PARAMETER: i32 = -8;
+
+/// RPC error code for a parameter of the wrong type or format
+pub(crate) const RPC_TYPE_ERROR: i32 = -3;
+
+/// RPC error code for an invalid or unknown address, key, transaction or block
+pub(crate) const RPC_INVALID_ADDRESS_OR_KEY: i32 = -5;
+
+/// RPC error code for data that could not be deserialized
+pub(crate) const RPC_DESERIALIZATION_ERROR: i32 = -22;
+
+/// RPC error code for a method the node doesn't provide
+pub(crate) const RPC_METHOD_NOT_FOUND: i32 = -32601;
+
+/// RPC error code for a peer that is already in the added node list
+pub(crate) const RPC_CLIENT_NODE_ALREADY_ADDED: i32 = -23;
+
+/// RPC error code for a peer that is not in the added node list
+pub(crate) const RPC_CLIENT_NODE_NOT_ADDED: i32 = -24;
+
+/// RPC error code for a wallet call that needs the wallet to be unlocked first
+pub(crate) const RPC_WALLET_UNLOCK_NEEDED: i32 = -13;
+
+/// RPC error code for a general wallet failure, such as a failed backup
+pub(crate) const RPC_WALLET_ERROR: i32 = -4;
+
+/// The message the node returns from `stop`
+const STOP_MESSAGE: &str = "Bitcoin server stopping";
+
+/// The start of the message the node returns from `encryptwallet`
+const ENCRYPT_WALLET_MESSAGE: &str =
+    "wallet encrypted; Bitcoin server stopping, restart to run with encrypted wallet";
 
 /// JSON-RPC request structure
+///
+/// The params are borrowed so that large requests are serialized straight into the body.
 #[derive(Debug, Serialize)]
-struct RpcRequest {
-    jsonrpc: String,
-    id: String,
-    method: String,
-    params: Vec<Value>,
+struct RpcRequest<'a, P> {
+    jsonrpc: &'a str,
+    id: &'a str,
+    method: &'a str,
+    params: &'a P,
 }
 
 /// JSON-RPC response structure
+///
+/// The result is borrowed from the response body as raw JSON, so that a `null` result (which
+/// many methods return on success) can be told apart from a missing one and then deserialized
+/// into the type the caller expects.
 #[derive(Debug, Deserialize)]
-struct RpcResponse<T> {
-    result: Option<T>,
+struct RpcResponse<'a> {
+    #[serde(borrow, default, deserialize_with = "present")]
+    result: Option<&'a RawValue>,
     error: Option<RpcError>,
     #[allow(dead_code)]
     id: String,
 }
 
+/// Deserializes a field that is present, keeping a `null` value as `Some`.
+fn present<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<&'de RawValue>, D::Error> {
+    <&RawValue>::deserialize(deserializer).map(Some)
+}
+
 /// JSON-RPC error structure
 #[derive(Debug, Deserialize)]
 struct RpcError {
@@ -33,12 +106,17 @@ struct RpcError {
 }
 
 /// Client for JSON-RPC communication with Bitcoin SV node
+///
+/// The URL is parsed and the authorization header built once, so that each call only
+/// serializes its request and reuses a pooled connection.
 #[derive(Clone)]
 pub(crate) struct RpcClient {
-    url: String,
+    url: Url,
+    /// Endpoint of the wallet to use on a node with several wallets, or `None` for the root URL
+    wallet_url: Option<Url>,
     client: Client,
-    username: Option<String>,
-    password: Option<String>,
+    /// Sorted names of the node's RPC methods, fetched on first use and shared between clones
+    methods: Arc<OnceCell<Vec<String>>>,
 }
 
 impl RpcClient {
@@ -50,93 +128,4592 @@ impl RpcClient {
             ));
         }
 
+        let url = Url::parse(url).map_err(|e| Error::InvalidUrl(e.to_string()))?;
+
+        // Credentials are sent with every request as a default header
+        let mut headers = HeaderMap::new();
+        if let (Some(username), Some(password)) = (username, password) {
+            let credentials = STANDARD.encode(format!("{}:{}", username, password));
+            let mut auth = HeaderValue::from_str(&format!("Basic {}", credentials))
+                .map_err(|e| Error::Config(e.to_string()))?;
+            auth.set_sensitive(true);
+            headers.insert(AUTHORIZATION, auth);
+        }
+
         Ok(Self {
-            url: url.to_string(),
-            client: Client::new(),
-            username,
-            password,
+            url,
+            wallet_url: None,
+            client: Client::builder().default_headers(headers).build()?,
+            methods: Arc::new(OnceCell::new()),
         })
     }
 
+    /// Returns a client sending wallet calls to the `/wallet/<name>` endpoint, sharing this
+    /// client's connections
+    pub fn with_wallet(&self, name: &str) -> Self {
+        let mut wallet_url = self.url.clone();
+        // Pushing a segment percent-encodes it, including any '/'
+        if let Ok(mut segments) = wallet_url.path_segments_mut() {
+            segments.pop_if_empty().push("wallet").push(name);
+        }
+        Self {
+            wallet_url: Some(wallet_url),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the endpoint for wallet calls
+    fn wallet_url(&self) -> &Url {
+        self.wallet_url.as_ref().unwrap_or(&self.url)
+    }
+
     /// Makes an RPC call to the node
+    ///
+    /// Methods that return `null` on success can be called with `T = ()`, and methods whose
+    /// result may be `null` with `T = Option<_>`.
     async fn call<T: for<'de> Deserialize<'de>>(
         &self,
         method: &str,
         params: Vec<Value>,
     ) -> Result<T> {
+        self.call_with(method, &params).await
+    }
+
+    /// Makes an RPC call to the node with params of any serializable type
+    ///
+    /// `params` must serialize to a JSON array.
+    async fn call_with<P: Serialize, T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        params: &P,
+    ) -> Result<T> {
+        let response = self.send(&self.url, method, params).await?;
+        let body = response.bytes().await?;
+        parse_response(&body)
+    }
+
+    /// Sends an RPC request to an endpoint of the node, returning the response once its headers
+    /// are received
+    async fn send<P: Serialize>(&self, url: &Url, method: &str, params: &P) -> Result<Response> {
         let request = RpcRequest {
-            jsonrpc: "1.0".to_string(),
-            id: "bitcoinsv-rpc".to_string(),
-            method: method.to_string(),
+            jsonrpc: "1.0",
+            id: "bitcoinsv-rpc",
+            method,
             params,
         };
 
-        let mut req = self.client.post(&self.url).json(&request);
+        Ok(self.client.post(url.clone()).json(&request).send().await?)
+    }
+
+    /// Makes an RPC call to a wallet method
+    ///
+    /// The call goes to the endpoint of the wallet chosen with [`Self::with_wallet`], if any.
+    /// A node without a wallet doesn't provide the wallet methods, which is reported as
+    /// [`Error::WalletUnavailable`]. Calls that need the keys of an encrypted, locked wallet
+    /// fail with [`Error::WalletLocked`].
+    async fn call_wallet<P: Serialize, T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        params: &P,
+    ) -> Result<T> {
+        let response = self.send(self.wallet_url(), method, params).await?;
+        let body = response.bytes().await?;
+        parse_response(&body).map_err(wallet_error)
+    }
 
-        // Add basic auth if credentials provided
-        if let (Some(username), Some(password)) = (&self.username, &self.password) {
-            req = req.basic_auth(username, Some(password));
+    /// Makes an RPC call after which the node shuts down, returning its message
+    ///
+    /// The node may close the connection while it shuts down. Once it has answered with a
+    /// success status the shutdown is underway, so a truncated body isn't an error and
+    /// `closed_message` is returned instead.
+    async fn call_shutdown<P: Serialize>(
+        &self,
+        url: &Url,
+        method: &str,
+        params: &P,
+        closed_message: &str,
+    ) -> Result<String> {
+        let response = self.send(url, method, params).await?;
+        let status = response.status();
+        match response.bytes().await {
+            Ok(body) => parse_response(&body),
+            Err(_) if status.is_success() => Ok(closed_message.to_string()),
+            Err(e) => Err(e.into()),
         }
+    }
+
+    /// Asks the node to shut down using `stop`
+    pub async fn stop(&self) -> Result<String> {
+        self.call_shutdown(&self.url, "stop", &Vec::<Value>::new(), STOP_MESSAGE)
+            .await
+    }
 
-        let response = req.send().await?;
-        let rpc_response: RpcResponse<T> = response.json().await?;
+    /// Encrypts the wallet with a passphrase using `encryptwallet`, after which the node shuts
+    /// down
+    pub async fn encrypt_wallet(&self, passphrase: &str) -> Result<String> {
+        validate_passphrase(passphrase)?;
+        self.call_shutdown(
+            self.wallet_url(),
+            "encryptwallet",
+            &(Passphrase(passphrase),),
+            ENCRYPT_WALLET_MESSAGE,
+        )
+        .await
+        .map_err(wallet_error)
+    }
 
-        if let Some(error) = rpc_response.error {
-            return Err(Error::Rpc {
-                code: error.code,
-                message: error.message,
-            });
-        }
+    /// Unlocks the wallet for `timeout_secs` seconds using `walletpassphrase`
+    pub async fn wallet_passphrase(&self, passphrase: &str, timeout_secs: u64) -> Result<()> {
+        validate_passphrase(passphrase)?;
+        self.call_wallet("walletpassphrase", &(Passphrase(passphrase), timeout_secs))
+            .await
+    }
 
-        rpc_response
-            .result
-            .ok_or_else(|| Error::Other("No result in RPC response".to_string()))
+    /// Changes the wallet passphrase using `walletpassphrasechange`
+    pub async fn wallet_passphrase_change(
+        &self,
+        old_passphrase: &str,
+        new_passphrase: &str,
+    ) -> Result<()> {
+        validate_passphrase(old_passphrase)?;
+        validate_passphrase(new_passphrase)?;
+        let params = (Passphrase(old_passphrase), Passphrase(new_passphrase));
+        self.call_wallet("walletpassphrasechange", &params).await
+    }
+
+    /// Locks the wallet using `walletlock`
+    pub async fn wallet_lock(&self) -> Result<()> {
+        self.call_wallet("walletlock", &Vec::<Value>::new()).await
     }
 
     /// Gets the best block hash from the node
     pub async fn get_best_block_hash(&self) -> Result<BlockHash> {
         let hash_str: String = self.call("getbestblockhash", vec![]).await?;
-        BlockHash::from_hex(&hash_str)
-            .map_err(|e| Error::BitcoinSv(format!("Failed to parse block hash: {}", e)))
+        parse_block_hash(&hash_str)
     }
 
-    /// Gets the block header for a given block hash
-    pub async fn get_block_header(&self, block_hash: &BlockHash) -> Result<BlockHeader> {
-        // Request verbose=false to get hex-encoded header
-        let hex: String = self
+    /// Gets the hash of the block at the given height in the best chain
+    pub async fn get_block_hash(&self, height: u64) -> Result<BlockHash> {
+        let hash_str: String = match self.call("getblockhash", vec![Value::from(height)]).await {
+            Err(Error::Rpc {
+                code: RPC_INVALID_PARAMETER,
+                ..
+            }) => return Err(Error::BlockHeightOutOfRange(height)),
+            result => result?,
+        };
+        parse_block_hash(&hash_str)
+    }
+
+    /// Gets the number of blocks in the longest chain
+    pub async fn get_block_count(&self) -> Result<u64> {
+        self.call("getblockcount", vec![]).await
+    }
+
+    /// Gets a transaction by its id
+    ///
+    /// Without `-txindex` the node can only find transactions in the mempool or with unspent
+    /// outputs.
+    pub async fn get_raw_transaction(&self, txid: &TxHash) -> Result<Tx> {
+        // Request verbose=false to get the hex-encoded transaction
+        let hex: String = match self
             .call(
-                "getblockheader",
-                vec![Value::String(block_hash.to_string()), Value::Bool(false)],
+                "getrawtransaction",
+                vec![Value::String(txid.to_string()), Value::Bool(false)],
             )
-            .await?;
+            .await
+        {
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                ..
+            }) => return Err(transaction_not_found(txid)),
+            result => result?,
+        };
 
-        // Decode hex to bytes
         let bytes = hex::decode(&hex)?;
+        Tx::from_binary(&mut &bytes[..])
+            .map_err(|e| Error::BitcoinSv(format!("Failed to parse transaction: {}", e)))
+    }
 
-        // Parse using bitcoinsv crate
-        BlockHeader::from_binary(&mut &bytes[..])
-            .map_err(|e| Error::BitcoinSv(format!("Failed to parse block header: {}", e)))
+    /// Gets a transaction decoded by the node, with its confirmation metadata
+    pub async fn get_raw_transaction_info(&self, txid: &TxHash) -> Result<TransactionInfo> {
+        match self
+            .call(
+                "getrawtransaction",
+                vec![Value::String(txid.to_string()), Value::Bool(true)],
+            )
+            .await
+        {
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                ..
+            }) => Err(transaction_not_found(txid)),
+            result => result,
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Submits a hex-encoded transaction to the node for validation and relay
+    ///
+    /// `allow_high_fees` and `dont_check_fee` map to the SV node's `allowhighfees` and
+    /// `dontcheckfee` flags. Rejections are returned as [`Error::Rpc`] with the node's code and
+    /// message.
+    pub async fn send_raw_transaction(
+        &self,
+        tx_hex: &str,
+        allow_high_fees: bool,
+        dont_check_fee: bool,
+    ) -> Result<TxHash> {
+        let txid: String = self
+            .call(
+                "sendrawtransaction",
+                vec![
+                    Value::String(tx_hex.to_string()),
+                    Value::Bool(allow_high_fees),
+                    Value::Bool(dont_check_fee),
+                ],
+            )
+            .await?;
+        parse_tx_hash(&txid)
+    }
 
-    #[test]
-    fn test_rpc_client_creation() {
-        let client = RpcClient::new(
-            "http://localhost:8332",
-            Some("user".to_string()),
-            Some("pass".to_string()),
-        );
-        assert!(client.is_ok());
+    /// Submits a batch of transactions using the SV `sendrawtransactions` RPC
+    pub async fn send_raw_transactions(
+        &self,
+        txs: &[RawTxSubmission],
+    ) -> Result<SendRawTransactionsResult> {
+        // The submissions are serialized directly into the request body
+        self.call_with("sendrawtransactions", &(txs,)).await
     }
 
-    #[test]
-    fn test_rpc_client_invalid_url() {
-        let client = RpcClient::new("localhost:8332", None, None);
-        assert!(client.is_err());
+    /// Creates an unsigned transaction spending the given inputs, returned as hex
+    pub async fn create_raw_transaction(
+        &self,
+        inputs: &[TxInput],
+        outputs: &[(String, u64)],
+        locktime: Option<u32>,
+    ) -> Result<String> {
+        let outputs = RawTxOutputs(outputs);
+        match locktime {
+            Some(locktime) => {
+                self.call_with("createrawtransaction", &(inputs, outputs, locktime))
+                    .await
+            }
+            None => {
+                self.call_with("createrawtransaction", &(inputs, outputs))
+                    .await
+            }
+        }
+    }
+
+    /// Decodes a hex-encoded transaction using the node's decoder
+    pub async fn decode_raw_transaction(&self, tx_hex: &str) -> Result<DecodedTransaction> {
+        self.call(
+            "decoderawtransaction",
+            vec![Value::String(tx_hex.to_string())],
+        )
+        .await
+    }
+
+    /// Adds inputs and a change output to a transaction using the node's wallet
+    pub async fn fund_raw_transaction(
+        &self,
+        tx_hex: &str,
+        options: &FundRawOptions,
+    ) -> Result<FundRawResult> {
+        self.call_wallet("fundrawtransaction", &(tx_hex, options))
+            .await
+    }
+
+    /// Gets an unspent transaction output, or `None` if it is spent or unknown
+    pub async fn get_tx_out(
+        &self,
+        txid: &TxHash,
+        vout: u32,
+        include_mempool: bool,
+    ) -> Result<Option<TxOutInfo>> {
+        // The node returns null for outputs that are spent or don't exist
+        self.call(
+            "gettxout",
+            vec![
+                Value::String(txid.to_string()),
+                Value::from(vout),
+                Value::Bool(include_mempool),
+            ],
+        )
+        .await
+    }
+
+    /// Gets many unspent transaction outputs at once using the SV `gettxouts` RPC
+    ///
+    /// The result has one entry per outpoint, `None` for outputs that are spent or unknown.
+    pub async fn get_tx_outs(
+        &self,
+        outpoints: &[(TxHash, u32)],
+        return_fields: Option<&[&str]>,
+        include_mempool: bool,
+    ) -> Result<Vec<Option<TxOutsEntry>>> {
+        let outpoints: Vec<_> = outpoints.iter().map(TxOutsOutpoint).collect();
+        let return_fields = return_fields.unwrap_or(&["*"]);
+        let result: TxOutsResult = self
+            .call_with("gettxouts", &(&outpoints, return_fields, include_mempool))
+            .await?;
+
+        if result.txouts.len() != outpoints.len() {
+            return Err(Error::Other(format!(
+                "gettxouts returned {} entries for {} outpoints",
+                result.txouts.len(),
+                outpoints.len()
+            )));
+        }
+        Ok(result.txouts)
+    }
+
+    /// Gets a serialized merkle block proving that the given transactions are in a block
+    ///
+    /// Errors from the node, such as transactions not being found in the block, are returned
+    /// unchanged as [`Error::Rpc`].
+    pub async fn get_tx_out_proof(
+        &self,
+        txids: &[TxHash],
+        block_hash: Option<&BlockHash>,
+    ) -> Result<Vec<u8>> {
+        let txids = txids
+            .iter()
+            .map(|txid| Value::String(txid.to_string()))
+            .collect();
+        let params = optional_params(vec![
+            Some(Value::Array(txids)),
+            block_hash.map(|hash| Value::String(hash.to_string())),
+        ]);
+        let hex: String = self.call("gettxoutproof", params).await?;
+        Ok(hex::decode(hex)?)
+    }
+
+    /// Verifies a serialized merkle block, returning the transactions it proves
+    ///
+    /// The node returns an empty list when the proof doesn't match the block's merkle root,
+    /// which is reported as [`Error::InvalidProof`].
+    pub async fn verify_tx_out_proof(&self, proof: &[u8]) -> Result<Vec<TxHash>> {
+        let txids: Vec<HexHash<TxHash>> = self
+            .call("verifytxoutproof", vec![Value::String(hex::encode(proof))])
+            .await?;
+        if txids.is_empty() {
+            return Err(Error::InvalidProof(
+                "merkle block does not commit to any transaction".to_string(),
+            ));
+        }
+        Ok(txids.into_iter().map(|HexHash(txid)| txid).collect())
+    }
+
+    /// Gets a merkle proof for a transaction using the SV `getmerkleproof` RPC
+    pub async fn get_merkle_proof(
+        &self,
+        txid: &TxHash,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<MerkleProof> {
+        let params = optional_params(vec![
+            Some(Value::String(txid.to_string())),
+            block_hash.map(|hash| Value::String(hash.to_string())),
+        ]);
+        self.call("getmerkleproof", params).await
+    }
+
+    /// Gets a merkle proof in the TSC format using the SV `getmerkleproof2` RPC
+    pub async fn get_merkle_proof2(
+        &self,
+        block_hash: &BlockHash,
+        txid: &TxHash,
+        include_full_tx: bool,
+        target_type: TargetType,
+    ) -> Result<TscMerkleProof> {
+        self.call(
+            "getmerkleproof2",
+            vec![
+                Value::String(block_hash.to_string()),
+                Value::String(txid.to_string()),
+                Value::Bool(include_full_tx),
+                Value::from(target_type.as_param()),
+            ],
+        )
+        .await
+    }
+
+    /// Verifies a TSC-format merkle proof using the SV `verifymerkleproof` RPC
+    pub async fn verify_merkle_proof(&self, proof: &TscMerkleProof) -> Result<bool> {
+        match self.call_with("verifymerkleproof", &(proof,)).await {
+            Err(Error::Rpc {
+                code: RPC_INVALID_PARAMETER | RPC_DESERIALIZATION_ERROR,
+                message,
+            }) => Err(Error::InvalidProof(message)),
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                message,
+            }) => Err(Error::NotFound(message)),
+            result => result,
+        }
+    }
+
+    /// Gets the current state of the mempool
+    pub async fn get_mempool_info(&self) -> Result<MempoolInfo> {
+        self.call("getmempoolinfo", vec![]).await
+    }
+
+    /// Gets the ids of all transactions in the mempool
+    pub async fn get_raw_mempool(&self) -> Result<Vec<TxHash>> {
+        // Each txid is parsed straight from the response body, without a String per entry
+        let txids: Vec<HexHash<TxHash>> =
+            self.call("getrawmempool", vec![Value::Bool(false)]).await?;
+        Ok(txids.into_iter().map(|HexHash(txid)| txid).collect())
+    }
+
+    /// Gets all transactions in the mempool with their mempool details
+    pub async fn get_raw_mempool_verbose(&self) -> Result<HashMap<TxHash, MempoolEntry>> {
+        // Entries are inserted into the map as they are parsed from the response body
+        let HexHashMap(entries) = self.call("getrawmempool", vec![Value::Bool(true)]).await?;
+        Ok(entries)
+    }
+
+    /// Gets the mempool details of a single transaction
+    pub async fn get_mempool_entry(&self, txid: &TxHash) -> Result<MempoolEntry> {
+        match self
+            .call("getmempoolentry", vec![Value::String(txid.to_string())])
+            .await
+        {
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                ..
+            }) => Err(not_in_mempool(txid)),
+            result => result,
+        }
+    }
+
+    /// Gets the ids of the in-mempool ancestors of a mempool transaction
+    pub async fn get_mempool_ancestors(&self, txid: &TxHash) -> Result<Vec<TxHash>> {
+        let txids: Vec<HexHash<TxHash>> = self
+            .call_mempool_relatives("getmempoolancestors", txid, false)
+            .await?;
+        Ok(txids.into_iter().map(|HexHash(txid)| txid).collect())
+    }
+
+    /// Gets the in-mempool ancestors of a mempool transaction with their mempool details
+    pub async fn get_mempool_ancestors_verbose(
+        &self,
+        txid: &TxHash,
+    ) -> Result<HashMap<TxHash, MempoolEntry>> {
+        let HexHashMap(entries) = self
+            .call_mempool_relatives("getmempoolancestors", txid, true)
+            .await?;
+        Ok(entries)
+    }
+
+    /// Gets the ids of the in-mempool descendants of a mempool transaction
+    pub async fn get_mempool_descendants(&self, txid: &TxHash) -> Result<Vec<TxHash>> {
+        let txids: Vec<HexHash<TxHash>> = self
+            .call_mempool_relatives("getmempooldescendants", txid, false)
+            .await?;
+        Ok(txids.into_iter().map(|HexHash(txid)| txid).collect())
+    }
+
+    /// Gets the in-mempool descendants of a mempool transaction with their mempool details
+    pub async fn get_mempool_descendants_verbose(
+        &self,
+        txid: &TxHash,
+    ) -> Result<HashMap<TxHash, MempoolEntry>> {
+        let HexHashMap(entries) = self
+            .call_mempool_relatives("getmempooldescendants", txid, true)
+            .await?;
+        Ok(entries)
+    }
+
+    /// Calls `getmempoolancestors` or `getmempooldescendants`, mapping a transaction that is
+    /// not in the mempool to [`Error::NotFound`]
+    async fn call_mempool_relatives<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        txid: &TxHash,
+        verbose: bool,
+    ) -> Result<T> {
+        match self
+            .call(
+                method,
+                vec![Value::String(txid.to_string()), Value::Bool(verbose)],
+            )
+            .await
+        {
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                ..
+            }) => Err(not_in_mempool(txid)),
+            result => result,
+        }
+    }
+
+    /// Writes the mempool to disk
+    pub async fn save_mempool(&self) -> Result<()> {
+        self.call("savemempool", vec![]).await
+    }
+
+    /// Checks the mempool journal for consistency with the mempool
+    pub async fn check_journal(&self) -> Result<JournalCheckResult> {
+        self.call("checkjournal", vec![]).await
+    }
+
+    /// Rebuilds the mempool journal from the mempool
+    pub async fn rebuild_journal(&self) -> Result<()> {
+        self.call("rebuildjournal", vec![]).await
+    }
+
+    /// Gets mining-related state of the node
+    pub async fn get_mining_info(&self) -> Result<MiningInfo> {
+        self.call("getmininginfo", vec![]).await
+    }
+
+    /// Gets a block to mine using the SV `getminingcandidate` RPC
+    pub async fn get_mining_candidate(&self, provide_coinbase_tx: bool) -> Result<MiningCandidate> {
+        self.call("getminingcandidate", vec![Value::Bool(provide_coinbase_tx)])
+            .await
+    }
+
+    /// Submits a solved mining candidate using the SV `submitminingsolution` RPC
+    ///
+    /// The node returns `true` (or `null`) when it accepts the block and the reject reason as a
+    /// string otherwise, which is returned as [`Error::BlockRejected`].
+    pub async fn submit_mining_solution(&self, solution: &MiningSolution) -> Result<()> {
+        let result: Value = self.call_with("submitminingsolution", &(solution,)).await?;
+        match result {
+            Value::Null | Value::Bool(true) => Ok(()),
+            Value::String(reason) => Err(Error::BlockRejected(reason)),
+            other => Err(Error::BlockRejected(other.to_string())),
+        }
+    }
+
+    /// Gets a block template using `getblocktemplate`
+    pub async fn get_block_template(
+        &self,
+        request: Option<&BlockTemplateRequest>,
+    ) -> Result<BlockTemplate> {
+        match request {
+            Some(request) => self.call_with("getblocktemplate", &(request,)).await,
+            None => self.call("getblocktemplate", vec![]).await,
+        }
+    }
+
+    /// Submits a serialized block using `submitblock`
+    ///
+    /// Returns `None` if the block was accepted, or the node's result string (e.g. `duplicate`,
+    /// `inconclusive` or a reject reason) otherwise.
+    pub async fn submit_block(&self, block_hex: &str) -> Result<Option<String>> {
+        self.call("submitblock", vec![Value::from(block_hex)]).await
+    }
+
+    /// Mines blocks to an address using `generatetoaddress` (regtest only)
+    pub async fn generate_to_address(
+        &self,
+        nblocks: u32,
+        address: &str,
+        max_tries: Option<u32>,
+    ) -> Result<Vec<BlockHash>> {
+        let params = optional_params(vec![
+            Some(Value::from(nblocks)),
+            Some(Value::from(address)),
+            max_tries.map(Value::from),
+        ]);
+        let hashes: Vec<HexHash<BlockHash>> = self.call("generatetoaddress", params).await?;
+        Ok(hashes.into_iter().map(|HexHash(hash)| hash).collect())
+    }
+
+    /// Mines blocks to a wallet key using `generate` (regtest only)
+    pub async fn generate(&self, nblocks: u32, max_tries: Option<u32>) -> Result<Vec<BlockHash>> {
+        let params = optional_params(vec![Some(Value::from(nblocks)), max_tries.map(Value::from)]);
+        // The wallet calls report the node's method-not-found error as a missing wallet, but
+        // `generate` is also missing from newer nodes that have a wallet, and the two can't be
+        // told apart
+        let hashes: Vec<HexHash<BlockHash>> = match self.call_wallet("generate", &params).await {
+            Err(Error::WalletUnavailable) => {
+                return Err(Error::UnsupportedMethod(
+                    "generate is not available (wallet disabled or removed from this node), \
+                     use generate_to_address instead"
+                        .to_string(),
+                ))
+            }
+            result => result?,
+        };
+        Ok(hashes.into_iter().map(|HexHash(hash)| hash).collect())
+    }
+
+    /// Estimates the fee rate needed for confirmation within `nblocks` blocks using `estimatefee`
+    pub async fn estimate_fee(&self, nblocks: Option<u32>) -> Result<FeeRate> {
+        let params = optional_params(vec![nblocks.map(Value::from)]);
+        // The node returns -1 when it has no estimate
+        let SignedAmount(sats_per_kb) = self.call("estimatefee", params).await?;
+        u64::try_from(sats_per_kb)
+            .map(FeeRate::from_sats_per_kb)
+            .map_err(|_| Error::NoFeeEstimate)
+    }
+
+    /// Gets information about the node's connected peers using `getpeerinfo`
+    pub async fn get_peer_info(&self) -> Result<Vec<PeerInfo>> {
+        self.call("getpeerinfo", vec![]).await
+    }
+
+    /// Gets the number of connections to other nodes using `getconnectioncount`
+    pub async fn get_connection_count(&self) -> Result<u64> {
+        self.call("getconnectioncount", vec![]).await
+    }
+
+    /// Gets the node's network traffic totals using `getnettotals`
+    pub async fn get_net_totals(&self) -> Result<NetTotals> {
+        self.call("getnettotals", vec![]).await
+    }
+
+    /// Adds, removes or tries a peer connection using `addnode`
+    pub async fn add_node(&self, addr: &str, command: AddNodeCommand) -> Result<()> {
+        let params = vec![Value::from(addr), Value::from(command.as_param())];
+        match self.call("addnode", params).await {
+            Err(Error::Rpc {
+                code: RPC_CLIENT_NODE_ALREADY_ADDED,
+                ..
+            }) => Err(Error::NodeAlreadyAdded(addr.to_string())),
+            Err(Error::Rpc {
+                code: RPC_CLIENT_NODE_NOT_ADDED,
+                ..
+            }) => Err(Error::NodeNotAdded(addr.to_string())),
+            result => result,
+        }
+    }
+
+    /// Disconnects a peer using `disconnectnode`
+    pub async fn disconnect_node(&self, target: &DisconnectTarget) -> Result<()> {
+        let params = match target {
+            DisconnectTarget::Address(addr) => vec![Value::from(addr.as_str())],
+            // The address must be empty when disconnecting by node id
+            DisconnectTarget::NodeId(id) => vec![Value::from(""), Value::from(*id)],
+        };
+        self.call("disconnectnode", params).await
+    }
+
+    /// Gets information about peers added with `addnode` using `getaddednodeinfo`
+    pub async fn get_added_node_info(&self, node: Option<&str>) -> Result<Vec<AddedNodeInfo>> {
+        let params = optional_params(vec![node.map(Value::from)]);
+        match self.call("getaddednodeinfo", params).await {
+            Err(Error::Rpc {
+                code: RPC_CLIENT_NODE_NOT_ADDED,
+                ..
+            }) => Err(Error::NodeNotAdded(node.unwrap_or_default().to_string())),
+            result => result,
+        }
+    }
+
+    /// Bans or unbans an IP address or subnet using `setban`
+    pub async fn set_ban(
+        &self,
+        subnet: &str,
+        command: BanCommand,
+        ban_time_secs: Option<u64>,
+        absolute: bool,
+    ) -> Result<()> {
+        validate_subnet(subnet)?;
+        let params = optional_params(vec![
+            Some(Value::from(subnet)),
+            Some(Value::from(command.as_param())),
+            ban_time_secs.map(Value::from),
+            absolute.then_some(Value::Bool(true)),
+        ]);
+        self.call("setban", params).await
+    }
+
+    /// Gets the banned addresses and subnets using `listbanned`
+    pub async fn list_banned(&self) -> Result<Vec<BannedEntry>> {
+        self.call("listbanned", vec![]).await
+    }
+
+    /// Removes all bans using `clearbanned`
+    pub async fn clear_banned(&self) -> Result<()> {
+        self.call("clearbanned", vec![]).await
+    }
+
+    /// Enables or disables P2P networking using `setnetworkactive`
+    pub async fn set_network_active(&self, active: bool) -> Result<bool> {
+        self.call("setnetworkactive", vec![Value::Bool(active)])
+            .await
+    }
+
+    /// Queues a ping to all peers using `ping`
+    pub async fn ping(&self) -> Result<()> {
+        self.call("ping", vec![]).await
+    }
+
+    /// Measures the round trip time of a `getbestblockhash` call
+    pub async fn measure_latency(&self) -> Result<Duration> {
+        let start = Instant::now();
+        // The result isn't needed, so it isn't parsed
+        let _: IgnoredAny = self.call("getbestblockhash", vec![]).await?;
+        Ok(start.elapsed())
+    }
+
+    /// Gets how long the node has been running using `uptime`
+    pub async fn uptime(&self) -> Result<Duration> {
+        let secs: u64 = self.call("uptime", vec![]).await?;
+        Ok(Duration::from_secs(secs))
+    }
+
+    /// Gets the node's memory usage using `getmemoryinfo`
+    pub async fn get_memory_info(&self) -> Result<MemoryInfo> {
+        self.call("getmemoryinfo", vec![Value::from("stats")]).await
+    }
+
+    /// Gets the allocator's statistics as XML using `getmemoryinfo` in `mallocinfo` mode
+    pub async fn get_malloc_info(&self) -> Result<String> {
+        self.call("getmemoryinfo", vec![Value::from("mallocinfo")])
+            .await
+    }
+
+    /// Gets a summary of the node's state using the legacy `getinfo`
+    pub async fn get_info(&self) -> Result<NodeInfo> {
+        self.call("getinfo", vec![]).await
+    }
+
+    /// Verifies the scripts of transaction inputs using `verifyscript`
+    pub async fn verify_script(
+        &self,
+        checks: &[ScriptCheck],
+        stop_on_first_invalid: bool,
+        timeout_secs: Option<u64>,
+    ) -> Result<Vec<ScriptCheckResult>> {
+        // The node's timeout is in milliseconds
+        let timeout_ms = timeout_secs.map(|secs| secs.saturating_mul(1000));
+        match timeout_ms {
+            Some(ms) => {
+                self.call_with("verifyscript", &(checks, stop_on_first_invalid, ms))
+                    .await
+            }
+            None => {
+                self.call_with("verifyscript", &(checks, stop_on_first_invalid))
+                    .await
+            }
+        }
+    }
+
+    /// Gets the node's safe mode state using `getsafemodeinfo`
+    pub async fn get_safe_mode_info(&self) -> Result<SafeModeInfo> {
+        self.call("getsafemodeinfo", vec![]).await
+    }
+
+    /// Excludes a block from safe mode consideration using `ignoresafemodeforblock`
+    pub async fn ignore_safe_mode_for_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.call(
+            "ignoresafemodeforblock",
+            vec![Value::String(block_hash.to_string())],
+        )
+        .await
+    }
+
+    /// Includes a block in safe mode consideration again using `reconsidersafemodeforblock`
+    pub async fn reconsider_safe_mode_for_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.call(
+            "reconsidersafemodeforblock",
+            vec![Value::String(block_hash.to_string())],
+        )
+        .await
+    }
+
+    /// Adds outputs to the policy blacklist using `addToPolicyBlacklist`
+    pub async fn add_to_policy_blacklist(&self, txos: &[FrozenTxo]) -> Result<BlacklistResult> {
+        self.call_policy_blacklist("addToPolicyBlacklist", txos)
+            .await
+    }
+
+    /// Removes outputs from the policy blacklist using `removeFromPolicyBlacklist`
+    pub async fn remove_from_policy_blacklist(
+        &self,
+        txos: &[FrozenTxo],
+    ) -> Result<BlacklistResult> {
+        self.call_policy_blacklist("removeFromPolicyBlacklist", txos)
+            .await
+    }
+
+    /// Calls a policy blacklist method, which only takes the outputs themselves
+    async fn call_policy_blacklist(
+        &self,
+        method: &str,
+        txos: &[FrozenTxo],
+    ) -> Result<BlacklistResult> {
+        let funds: Vec<PolicyFund> = txos.iter().map(PolicyFund).collect();
+        self.call_with(method, &(Funds { funds: &funds },)).await
+    }
+
+    /// Adds outputs to the consensus blacklist using `addToConsensusBlacklist`
+    pub async fn add_to_consensus_blacklist(&self, txos: &[FrozenTxo]) -> Result<BlacklistResult> {
+        self.call_with("addToConsensusBlacklist", &(Funds { funds: txos },))
+            .await
+    }
+
+    /// Lists the frozen outputs using `queryBlacklist`
+    pub async fn query_blacklist(&self) -> Result<Vec<BlacklistEntry>> {
+        let query: BlacklistQuery = self.call("queryBlacklist", vec![]).await?;
+        Ok(query.funds)
+    }
+
+    /// Removes entries from the blacklists using `clearBlacklists`, returning how many were removed
+    pub async fn clear_blacklists(
+        &self,
+        remove_all_entries: bool,
+        expiration_height_delta: Option<u64>,
+    ) -> Result<u64> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ClearParams {
+            remove_all_entries: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            expiration_height_delta: Option<u64>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ClearResult {
+            num_removed_entries: u64,
+        }
+
+        let params = ClearParams {
+            remove_all_entries,
+            expiration_height_delta,
+        };
+        let result: ClearResult = self.call_with("clearBlacklists", &(params,)).await?;
+        Ok(result.num_removed_entries)
+    }
+
+    /// Validates an address using `validateaddress`
+    pub async fn validate_address(&self, address: &str) -> Result<AddressValidation> {
+        self.call("validateaddress", vec![Value::from(address)])
+            .await
+    }
+
+    /// Verifies a signed message using `verifymessage`
+    ///
+    /// A signature that isn't valid base64 is reported as `false` rather than as an error.
+    pub async fn verify_message(
+        &self,
+        address: &str,
+        signature_base64: &str,
+        message: &str,
+    ) -> Result<bool> {
+        let params = vec![
+            Value::from(address),
+            Value::from(signature_base64),
+            Value::from(message),
+        ];
+        match self.call("verifymessage", params).await {
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                message,
+            }) if message.contains("Malformed base64") => Ok(false),
+            result => result,
+        }
+    }
+
+    /// Signs a message with the key of a wallet address using `signmessage`
+    ///
+    /// Returns the signature as base64.
+    pub async fn sign_message(&self, address: &str, message: &str) -> Result<String> {
+        match self.call_wallet("signmessage", &(address, message)).await {
+            // Type error: not a key address, wallet error: the key isn't in the wallet
+            Err(Error::Rpc {
+                code: RPC_TYPE_ERROR | RPC_WALLET_ERROR,
+                ..
+            }) => Err(Error::KeyNotInWallet(address.to_string())),
+            result => result,
+        }
+    }
+
+    /// Creates a multisig address using `createmultisig`
+    pub async fn create_multisig(&self, required: u32, keys: &[String]) -> Result<MultisigInfo> {
+        validate_multisig(required, keys)?;
+        self.call_with("createmultisig", &(required, keys)).await
+    }
+
+    /// Adds a multisig address to the wallet using `addmultisigaddress`
+    ///
+    /// Returns the new P2SH address.
+    pub async fn add_multisig_address(
+        &self,
+        required: u32,
+        keys: &[String],
+        label: Option<&str>,
+    ) -> Result<String> {
+        validate_multisig(required, keys)?;
+        match label {
+            Some(label) => {
+                self.call_wallet("addmultisigaddress", &(required, keys, label))
+                    .await
+            }
+            None => {
+                self.call_wallet("addmultisigaddress", &(required, keys))
+                    .await
+            }
+        }
+    }
+
+    /// Refills the wallet's key pool using `keypoolrefill`
+    pub async fn key_pool_refill(&self, new_size: Option<u32>) -> Result<()> {
+        let params = optional_params(vec![new_size.map(Value::from)]);
+        self.call_wallet("keypoolrefill", &params).await
+    }
+
+    /// Sets the wallet's fee rate using `settxfee`
+    pub async fn set_tx_fee(&self, fee: FeeRate) -> Result<bool> {
+        self.call_wallet("settxfee", &(ser::Amount(fee.sats_per_kb()),))
+            .await
+    }
+
+    /// Gets the names of the node's RPC methods, sorted, from the output of `help`
+    ///
+    /// The list is fetched once and then served from the cache.
+    pub async fn list_methods(&self) -> Result<&[String]> {
+        let methods = self
+            .methods
+            .get_or_try_init(|| async {
+                let help: String = self.call("help", vec![]).await?;
+                Ok::<_, Error>(parse_help_methods(&help))
+            })
+            .await?;
+        Ok(methods)
+    }
+
+    /// Gets whether the node provides an RPC method
+    pub async fn supports_method(&self, method: &str) -> Result<bool> {
+        let methods = self.list_methods().await?;
+        Ok(methods
+            .binary_search_by(|name| name.as_str().cmp(method))
+            .is_ok())
+    }
+
+    /// Gets a new address from the wallet using `getnewaddress`
+    pub async fn get_new_address(&self, label: Option<&str>) -> Result<String> {
+        let params = optional_params(vec![label.map(Value::from)]);
+        self.call_wallet("getnewaddress", &params).await
+    }
+
+    /// Gets the wallet's balance in satoshis using `getbalance`
+    pub async fn get_balance(
+        &self,
+        min_conf: Option<u32>,
+        include_watch_only: bool,
+    ) -> Result<u64> {
+        // The legacy account parameter must be "*" (all accounts) to pass the later parameters
+        let params = if min_conf.is_some() || include_watch_only {
+            vec![
+                Value::from("*"),
+                Value::from(min_conf.unwrap_or(1)),
+                Value::Bool(include_watch_only),
+            ]
+        } else {
+            vec![]
+        };
+        let de::Amount(sats) = self.call_wallet("getbalance", &params).await?;
+        Ok(sats)
+    }
+
+    /// Gets the wallet's unconfirmed balance in satoshis using `getunconfirmedbalance`
+    pub async fn get_unconfirmed_balance(&self) -> Result<u64> {
+        let de::Amount(sats) = self
+            .call_wallet("getunconfirmedbalance", &Vec::<Value>::new())
+            .await?;
+        Ok(sats)
+    }
+
+    /// Lists the wallet's unspent outputs using `listunspent`
+    pub async fn list_unspent(
+        &self,
+        min_conf: Option<u32>,
+        max_conf: Option<u32>,
+        addresses: Option<&[String]>,
+        include_unsafe: bool,
+    ) -> Result<Vec<Unspent>> {
+        // The node's defaults are filled in so that later parameters can be sent
+        let params =
+            if min_conf.is_some() || max_conf.is_some() || addresses.is_some() || !include_unsafe {
+                vec![
+                    Value::from(min_conf.unwrap_or(1)),
+                    Value::from(max_conf.unwrap_or(9_999_999)),
+                    Value::from(addresses.unwrap_or_default()),
+                    Value::Bool(include_unsafe),
+                ]
+            } else {
+                vec![]
+            };
+        self.call_wallet("listunspent", &params).await
+    }
+
+    /// Sends coins from the wallet to an address using `sendtoaddress`
+    pub async fn send_to_address(
+        &self,
+        address: &str,
+        amount_satoshis: u64,
+        comment: Option<&str>,
+        subtract_fee: bool,
+    ) -> Result<TxHash> {
+        let amount = ser::Amount(amount_satoshis);
+        let HexHash(txid) = if comment.is_some() || subtract_fee {
+            // An empty comment or comment_to is not stored by the node
+            let params = (address, amount, comment.unwrap_or(""), "", subtract_fee);
+            self.call_wallet("sendtoaddress", &params).await?
+        } else {
+            self.call_wallet("sendtoaddress", &(address, amount))
+                .await?
+        };
+        Ok(txid)
+    }
+
+    /// Sends coins from the wallet to several addresses in one transaction using `sendmany`
+    pub async fn send_many(
+        &self,
+        outputs: &HashMap<String, u64>,
+        min_conf: Option<u32>,
+        comment: Option<&str>,
+        subtract_fee_from: Option<&[String]>,
+    ) -> Result<TxHash> {
+        let subtract_fee_from = subtract_fee_from.unwrap_or_default();
+        // The node silently ignores addresses that aren't paid, which would leave the whole fee
+        // on the sender
+        if let Some(address) = subtract_fee_from
+            .iter()
+            .find(|address| !outputs.contains_key(*address))
+        {
+            return Err(Error::Config(format!(
+                "Cannot subtract the fee from {}, which is not one of the outputs",
+                address
+            )));
+        }
+
+        // The first parameter is the legacy account to send from, "" being the default account
+        let HexHash(txid) = self
+            .call_wallet(
+                "sendmany",
+                &(
+                    "",
+                    SendManyOutputs(outputs),
+                    min_conf.unwrap_or(1),
+                    comment.unwrap_or(""),
+                    subtract_fee_from,
+                ),
+            )
+            .await?;
+        Ok(txid)
+    }
+
+    /// Lists the wallet's most recent transactions using `listtransactions`
+    pub async fn list_transactions(
+        &self,
+        count: Option<u32>,
+        skip: Option<u32>,
+        include_watch_only: bool,
+    ) -> Result<Vec<WalletTxInfo>> {
+        // The legacy account parameter must be "*" (all accounts) to pass the later parameters
+        let params = if count.is_some() || skip.is_some() || include_watch_only {
+            vec![
+                Value::from("*"),
+                Value::from(count.unwrap_or(10)),
+                Value::from(skip.unwrap_or(0)),
+                Value::Bool(include_watch_only),
+            ]
+        } else {
+            vec![]
+        };
+        self.call_wallet("listtransactions", &params).await
+    }
+
+    /// Gets a transaction known to the wallet using `gettransaction`
+    pub async fn get_wallet_transaction(
+        &self,
+        txid: &TxHash,
+        include_watch_only: bool,
+    ) -> Result<WalletTransaction> {
+        let mut params = vec![Value::String(txid.to_string())];
+        if include_watch_only {
+            params.push(Value::Bool(true));
+        }
+        match self.call_wallet("gettransaction", &params).await {
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                ..
+            }) => Err(Error::NotFound(format!(
+                "transaction {} is not in the wallet",
+                txid
+            ))),
+            result => result,
+        }
+    }
+
+    /// Adds a private key to the wallet using `importprivkey`
+    ///
+    /// With `rescan` the node scans the whole chain for the key's transactions before
+    /// answering, which can take a long time.
+    pub async fn import_priv_key(
+        &self,
+        wif: &str,
+        label: Option<&str>,
+        rescan: bool,
+    ) -> Result<()> {
+        validate_wif(wif)?;
+        if label.is_none() && rescan {
+            self.call_wallet("importprivkey", &(wif,)).await
+        } else {
+            self.call_wallet("importprivkey", &(wif, label.unwrap_or(""), rescan))
+                .await
+        }
+    }
+
+    /// Adds a watch-only address or script to the wallet using `importaddress`
+    ///
+    /// With `rescan` the node scans the whole chain for the address's transactions before
+    /// answering, which can take a long time.
+    pub async fn import_address(
+        &self,
+        address_or_script: &str,
+        label: Option<&str>,
+        rescan: bool,
+        p2sh: bool,
+    ) -> Result<()> {
+        if label.is_none() && rescan && !p2sh {
+            self.call_wallet("importaddress", &(address_or_script,))
+                .await
+        } else {
+            let params = (address_or_script, label.unwrap_or(""), rescan, p2sh);
+            self.call_wallet("importaddress", &params).await
+        }
+    }
+
+    /// Gets the private key of a wallet address in WIF using `dumpprivkey`
+    pub async fn dump_priv_key(&self, address: &str) -> Result<String> {
+        self.call_wallet("dumpprivkey", &(address,)).await
+    }
+
+    /// Copies the wallet file to a path on the node's filesystem using `backupwallet`
+    pub async fn backup_wallet(&self, node_destination_path: &str) -> Result<()> {
+        match self
+            .call_wallet("backupwallet", &(node_destination_path,))
+            .await
+        {
+            // The node reports any failure to copy the file this way
+            Err(Error::Rpc {
+                code: RPC_WALLET_ERROR,
+                message,
+            }) => Err(Error::FileAccessDenied(message)),
+            result => result,
+        }
+    }
+
+    /// Writes all wallet keys to a file on the node's filesystem using `dumpwallet`
+    ///
+    /// Returns the full path of the file the node wrote.
+    pub async fn dump_wallet(&self, node_filename: &str) -> Result<String> {
+        #[derive(Deserialize)]
+        struct DumpWalletResult {
+            filename: String,
+        }
+
+        match self.call_wallet("dumpwallet", &(node_filename,)).await {
+            Ok(DumpWalletResult { filename }) => Ok(filename),
+            Err(Error::Rpc {
+                code: RPC_INVALID_PARAMETER,
+                message,
+            }) if message.contains("Cannot open") => Err(Error::FileAccessDenied(message)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets the state of the wallet using `getwalletinfo`
+    pub async fn get_wallet_info(&self) -> Result<WalletInfo> {
+        self.call_wallet("getwalletinfo", &Vec::<Value>::new())
+            .await
+    }
+
+    /// Lists the amounts received by each wallet address using `listreceivedbyaddress`
+    pub async fn list_received_by_address(
+        &self,
+        min_conf: Option<u32>,
+        include_empty: bool,
+        include_watch_only: bool,
+    ) -> Result<Vec<ReceivedByAddress>> {
+        let params = if min_conf.is_some() || include_empty || include_watch_only {
+            vec![
+                Value::from(min_conf.unwrap_or(1)),
+                Value::Bool(include_empty),
+                Value::Bool(include_watch_only),
+            ]
+        } else {
+            vec![]
+        };
+        self.call_wallet("listreceivedbyaddress", &params).await
+    }
+
+    /// Gets the total received by a wallet address in satoshis using `getreceivedbyaddress`
+    pub async fn get_received_by_address(
+        &self,
+        address: &str,
+        min_conf: Option<u32>,
+    ) -> Result<u64> {
+        let params = optional_params(vec![Some(Value::from(address)), min_conf.map(Value::from)]);
+        let de::Amount(sats) = self.call_wallet("getreceivedbyaddress", &params).await?;
+        Ok(sats)
+    }
+
+    /// Marks an unconfirmed wallet transaction as abandoned using `abandontransaction`
+    ///
+    /// The node refuses unknown and ineligible transactions with the same code, so they are
+    /// told apart by the message.
+    pub async fn abandon_transaction(&self, txid: &TxHash) -> Result<()> {
+        match self
+            .call_wallet("abandontransaction", &(txid.to_string(),))
+            .await
+        {
+            Err(Error::Rpc {
+                code: RPC_INVALID_ADDRESS_OR_KEY,
+                message,
+            }) if message.contains("non-wallet") => Err(Error::NotFound(format!(
+                "transaction {} is not in the wallet",
+                txid
+            ))),
+            result => result,
+        }
+    }
+
+    /// Locks or unlocks wallet outputs so that coin selection skips them, using `lockunspent`
+    pub async fn lock_unspent(&self, unlock: bool, outpoints: &[(TxHash, u32)]) -> Result<bool> {
+        let outpoints: Vec<TxInput> = outpoints
+            .iter()
+            .map(|&(txid, vout)| TxInput::new(txid, vout))
+            .collect();
+        self.call_wallet("lockunspent", &(unlock, outpoints)).await
+    }
+
+    /// Lists the wallet outputs locked with `lockunspent`, using `listlockunspent`
+    pub async fn list_lock_unspent(&self) -> Result<Vec<(TxHash, u32)>> {
+        #[derive(Deserialize)]
+        struct LockedOutpoint {
+            #[serde(deserialize_with = "de::hash")]
+            txid: TxHash,
+            vout: u32,
+        }
+
+        let locked: Vec<LockedOutpoint> = self
+            .call_wallet("listlockunspent", &Vec::<Value>::new())
+            .await?;
+        Ok(locked.into_iter().map(|o| (o.txid, o.vout)).collect())
+    }
+
+    /// Lists groups of wallet addresses whose common ownership has been revealed on chain,
+    /// using `listaddressgroupings`
+    pub async fn list_address_groupings(&self) -> Result<Vec<Vec<AddressGroupEntry>>> {
+        self.call_wallet("listaddressgroupings", &Vec::<Value>::new())
+            .await
+    }
+
+    /// Lists the names of the wallets loaded by the node using `listwallets`
+    pub async fn list_wallets(&self) -> Result<Vec<String>> {
+        // The list covers the whole node, so it is always fetched from the root endpoint
+        self.call("listwallets", vec![]).await.map_err(wallet_error)
+    }
+
+    /// Gets the current state of the blockchain
+    pub async fn get_blockchain_info(&self) -> Result<BlockchainInfo> {
+        self.call("getblockchaininfo", vec![]).await
+    }
+
+    /// Gets all known tips in the block tree, including the main chain and orphaned branches
+    pub async fn get_chain_tips(&self) -> Result<Vec<ChainTip>> {
+        self.call("getchaintips", vec![]).await
+    }
+
+    /// Gets transaction statistics for a window of blocks ending at the given block (or the tip)
+    pub async fn get_chain_tx_stats(
+        &self,
+        nblocks: Option<u32>,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<ChainTxStats> {
+        // If only the block hash is given, nblocks is sent as null so the node uses its default
+        // window (one month, limited by the height of the block)
+        let params = optional_params(vec![
+            nblocks.map(Value::from),
+            block_hash.map(|hash| Value::String(hash.to_string())),
+        ]);
+        self.call("getchaintxstats", params).await
+    }
+
+    /// Gets block metadata and the ids of its transactions
+    pub async fn get_block_info(&self, block_hash: &BlockHash) -> Result<BlockInfo> {
+        // Verbosity 1 returns the block as JSON with transaction ids only
+        self.call(
+            "getblock",
+            vec![Value::String(block_hash.to_string()), Value::from(1)],
+        )
+        .await
+    }
+
+    /// Gets a block with its transactions decoded by the node
+    pub async fn get_block_decoded(&self, block_hash: &BlockHash) -> Result<DecodedBlock> {
+        // Verbosity 2 returns the block as JSON with decoded transactions
+        self.call(
+            "getblock",
+            vec![Value::String(block_hash.to_string()), Value::from(2)],
+        )
+        .await
+    }
+
+    /// Gets the block at the given height in the best chain using the SV `getblockbyheight` RPC
+    ///
+    /// Verbosity 0 returns the parsed block, 1 the block metadata with transaction ids and 2 the
+    /// block with decoded transactions.
+    pub async fn get_block_by_height(&self, height: u64, verbosity: u8) -> Result<BlockData> {
+        let params = vec![Value::from(height), Value::from(verbosity)];
+        let result = match verbosity {
+            0 => {
+                let hex: String = self.call("getblockbyheight", params).await?;
+                let bytes = hex::decode(&hex)?;
+                Block::new(bytes.into())
+                    .map(BlockData::Raw)
+                    .map_err(|e| Error::BitcoinSv(format!("Failed to parse block: {}", e)))
+            }
+            1 => self
+                .call("getblockbyheight", params)
+                .await
+                .map(BlockData::Info),
+            2 => self
+                .call("getblockbyheight", params)
+                .await
+                .map(BlockData::Decoded),
+            _ => return Err(invalid_block_verbosity(verbosity)),
+        };
+        match result {
+            Err(Error::Rpc {
+                code: RPC_INVALID_PARAMETER,
+                ..
+            }) => Err(Error::BlockHeightOutOfRange(height)),
+            result => result,
+        }
+    }
+
+    /// Marks a block as invalid, as if it violated a consensus rule
+    pub async fn invalidate_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.call(
+            "invalidateblock",
+            vec![Value::String(block_hash.to_string())],
+        )
+        .await
+    }
+
+    /// Removes the invalidity status of a block and its descendants
+    pub async fn reconsider_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.call(
+            "reconsiderblock",
+            vec![Value::String(block_hash.to_string())],
+        )
+        .await
+    }
+
+    /// Waits for a new block to be connected and returns the new tip
+    ///
+    /// Returns the current tip if the timeout (in milliseconds) elapses first.
+    pub async fn wait_for_new_block(&self, timeout_ms: Option<u64>) -> Result<TipInfo> {
+        let params = optional_params(vec![timeout_ms.map(Value::from)]);
+        self.call("waitfornewblock", params).await
+    }
+
+    /// Waits for the best chain to reach the given height and returns the tip
+    ///
+    /// Returns the current tip if the timeout (in milliseconds) elapses first.
+    pub async fn wait_for_block_height(
+        &self,
+        height: u64,
+        timeout_ms: Option<u64>,
+    ) -> Result<TipInfo> {
+        let params = optional_params(vec![Some(Value::from(height)), timeout_ms.map(Value::from)]);
+        self.call("waitforblockheight", params).await
+    }
+
+    /// Waits for the block with the given hash to become the tip and returns the tip
+    ///
+    /// Returns the current tip if the timeout (in milliseconds) elapses first.
+    pub async fn wait_for_block(
+        &self,
+        block_hash: &BlockHash,
+        timeout_ms: Option<u64>,
+    ) -> Result<TipInfo> {
+        let params = optional_params(vec![
+            Some(Value::String(block_hash.to_string())),
+            timeout_ms.map(Value::from),
+        ]);
+        self.call("waitforblock", params).await
+    }
+
+    /// Gets the estimated network hash rate in hashes per second
+    pub async fn get_network_hash_ps(
+        &self,
+        nblocks: Option<i32>,
+        height: Option<u64>,
+    ) -> Result<f64> {
+        // If only the height is given, nblocks is sent as null so the node uses its default
+        let params = optional_params(vec![nblocks.map(Value::from), height.map(Value::from)]);
+        self.call("getnetworkhashps", params).await
+    }
+
+    /// Gets statistics for a block, optionally restricted to the named statistics
+    pub async fn get_block_stats(
+        &self,
+        target: BlockStatsTarget,
+        stats: Option<&[&str]>,
+    ) -> Result<BlockStats> {
+        // SV nodes take a block hash in getblockstats and a height in getblockstatsbyheight
+        let (method, target) = match target {
+            BlockStatsTarget::Hash(hash) => ("getblockstats", Value::String(hash.to_string())),
+            BlockStatsTarget::Height(height) => ("getblockstatsbyheight", Value::from(height)),
+        };
+        let params = optional_params(vec![Some(target), stats.map(Value::from)]);
+        self.call(method, params).await
+    }
+
+    /// Verifies the blockchain database, returning whether it is valid
+    pub async fn verify_chain(
+        &self,
+        check_level: Option<u32>,
+        num_blocks: Option<u32>,
+    ) -> Result<bool> {
+        let params = optional_params(vec![
+            check_level.map(Value::from),
+            num_blocks.map(Value::from),
+        ]);
+        self.call("verifychain", params).await
+    }
+
+    /// Gets the current proof-of-work difficulty
+    pub async fn get_difficulty(&self) -> Result<f64> {
+        // Depending on the node version this is returned as a number or a string
+        let difficulty: LenientF64 = self.call("getdifficulty", vec![]).await?;
+        Ok(difficulty.0)
+    }
+
+    /// Gets the block header for a given block hash
+    pub async fn get_block_header(&self, block_hash: &BlockHash) -> Result<BlockHeader> {
+        // Request verbose=false to get hex-encoded header
+        let hex: String = self
+            .call(
+                "getblockheader",
+                vec![Value::String(block_hash.to_string()), Value::Bool(false)],
+            )
+            .await?;
+
+        // Decode hex to bytes
+        let bytes = hex::decode(&hex)?;
+
+        // Parse using bitcoinsv crate
+        BlockHeader::from_binary(&mut &bytes[..])
+            .map_err(|e| Error::BitcoinSv(format!("Failed to parse block header: {}", e)))
+    }
+
+    /// Gets the block header for a given block hash together with its chain context
+    pub async fn get_block_header_info(&self, block_hash: &BlockHash) -> Result<BlockHeaderInfo> {
+        self.call(
+            "getblockheader",
+            vec![Value::String(block_hash.to_string()), Value::Bool(true)],
+        )
+        .await
+    }
+}
+
+/// Parses the body of a JSON-RPC response into the result type the caller expects.
+fn parse_response<T: for<'de> Deserialize<'de>>(body: &[u8]) -> Result<T> {
+    let rpc_response: RpcResponse = serde_json::from_slice(body)?;
+
+    if let Some(error) = rpc_response.error {
+        return Err(Error::Rpc {
+            code: error.code,
+            message: error.message,
+        });
+    }
+
+    let result = rpc_response
+        .result
+        .ok_or_else(|| Error::Other("No result in RPC response".to_string()))?;
+    Ok(serde_json::from_str(result.get())?)
+}
+
+/// Parses the method names from the output of `help`.
+///
+/// Each method is listed on its own line, followed by its arguments, under `== Category ==`
+/// headings.
+fn parse_help_methods(help: &str) -> Vec<String> {
+    let mut methods: Vec<String> = help
+        .lines()
+        .filter(|line| !line.starts_with("=="))
+        .filter_map(|line| line.split_whitespace().next())
+        .map(str::to_string)
+        .collect();
+    methods.sort();
+    methods.dedup();
+    methods
+}
+
+/// Builds positional parameters from a list of optional parameters.
+///
+/// Trailing unset parameters are omitted. Unset parameters that precede a set parameter are
+/// sent as `null`, which makes the node use its default for that position.
+fn optional_params(params: Vec<Option<Value>>) -> Vec<Value> {
+    let len = params
+        .iter()
+        .rposition(Option::is_some)
+        .map_or(0, |i| i + 1);
+    params
+        .into_iter()
+        .take(len)
+        .map(|param| param.unwrap_or(Value::Null))
+        .collect()
+}
+
+/// Returns the error for a `getblock`-style verbosity the library doesn't support.
+pub(crate) fn invalid_block_verbosity(verbosity: u8) -> Error {
+    Error::Config(format!(
+        "Unsupported block verbosity {}, expected 0, 1 or 2",
+        verbosity
+    ))
+}
+
+/// Returns the error for a transaction the node couldn't find.
+fn transaction_not_found(txid: &TxHash) -> Error {
+    Error::NotFound(format!(
+        "transaction {} (without -txindex the node only has mempool transactions and \
+         transactions with unspent outputs)",
+        txid
+    ))
+}
+
+/// Returns the error for a transaction that is not in the mempool.
+fn not_in_mempool(txid: &TxHash) -> Error {
+    Error::NotFound(format!("transaction {} is not in the mempool", txid))
+}
+
+/// Parses a block hash from the hex string returned by the node.
+///
+/// The node returns hashes in display (reversed) byte order, which is what `FromHex` expects.
+fn parse_block_hash(hash_str: &str) -> Result<BlockHash> {
+    BlockHash::from_hex(hash_str)
+        .map_err(|e| Error::BitcoinSv(format!("Failed to parse block hash: {}", e)))
+}
+
+/// Transaction outputs for `createrawtransaction`, serialized as an address-to-amount object.
+///
+/// The outputs are written in the given order, which determines their index in the
+/// transaction, and the amounts are written as exact decimal numbers.
+struct RawTxOutputs<'a>(&'a [(String, u64)]);
+
+impl Serialize for RawTxOutputs<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (address, sats) in self.0 {
+            map.serialize_entry(address, &ser::Amount(*sats))?;
+        }
+        map.end()
+    }
+}
+
+/// Payments for `sendmany`, serialized as an address-to-amount object with exact amounts.
+struct SendManyOutputs<'a>(&'a HashMap<String, u64>);
+
+impl Serialize for SendManyOutputs<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (address, sats) in self.0 {
+            map.serialize_entry(address, &ser::Amount(*sats))?;
+        }
+        map.end()
+    }
+}
+
+/// An outpoint as expected by `gettxouts`.
+struct TxOutsOutpoint<'a>(&'a (TxHash, u32));
+
+impl Serialize for TxOutsOutpoint<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let (txid, n) = self.0;
+        let mut state = serializer.serialize_struct("Outpoint", 2)?;
+        state.serialize_field("txid", &txid.to_string())?;
+        state.serialize_field("n", n)?;
+        state.end()
+    }
+}
+
+/// Parses a transaction id from the hex string returned by the node.
+fn parse_tx_hash(hash_str: &str) -> Result<TxHash> {
+    TxHash::from_hex(hash_str)
+        .map_err(|e| Error::BitcoinSv(format!("Failed to parse transaction id: {}", e)))
+}
+
+/// Checks that a `setban` subnet is an IP address, optionally followed by a prefix length or
+/// netmask (e.g. `192.0.2.0/24` or `192.0.2.0/255.255.255.0`).
+fn validate_subnet(subnet: &str) -> Result<()> {
+    let invalid = || Error::Config(format!("invalid IP address or subnet: {}", subnet));
+
+    let (addr, mask) = match subnet.split_once('/') {
+        Some((addr, mask)) => (addr, Some(mask)),
+        None => (subnet, None),
+    };
+    let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+    if let Some(mask) = mask {
+        let max_prefix = if addr.is_ipv4() { 32 } else { 128 };
+        let valid = match mask.parse::<u8>() {
+            Ok(prefix) => prefix <= max_prefix,
+            Err(_) => mask
+                .parse::<IpAddr>()
+                .is_ok_and(|mask| mask.is_ipv4() == addr.is_ipv4()),
+        };
+        if !valid {
+            return Err(invalid());
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a multisig script's required signature count is between 1 and the number of
+/// keys.
+fn validate_multisig(required: u32, keys: &[String]) -> Result<()> {
+    if required == 0 || required as usize > keys.len() {
+        return Err(Error::Config(format!(
+            "required signatures must be between 1 and the number of keys ({}), got {}",
+            keys.len(),
+            required
+        )));
+    }
+    Ok(())
+}
+
+/// Maps the errors of wallet calls to their typed variants.
+fn wallet_error(error: Error) -> Error {
+    match error {
+        Error::Rpc {
+            code: RPC_METHOD_NOT_FOUND,
+            ..
+        } => Error::WalletUnavailable,
+        Error::Rpc {
+            code: RPC_WALLET_UNLOCK_NEEDED,
+            ..
+        } => Error::WalletLocked,
+        error => error,
+    }
+}
+
+/// A wallet passphrase, which is sent to the node but never shown in debug output.
+struct Passphrase<'a>(&'a str);
+
+impl Serialize for Passphrase<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl fmt::Debug for Passphrase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Passphrase(<redacted>)")
+    }
+}
+
+/// Checks that a passphrase is not empty, which the node would reject.
+fn validate_passphrase(passphrase: &str) -> Result<()> {
+    if passphrase.is_empty() {
+        return Err(Error::Config("passphrase must not be empty".to_string()));
+    }
+    Ok(())
+}
+
+/// Checks that a private key looks like WIF: 51 (uncompressed) or 52 (compressed) base58
+/// characters. The key itself is left out of the error, as it may be a real secret.
+fn validate_wif(wif: &str) -> Result<()> {
+    const BASE58: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    if !matches!(wif.len(), 51 | 52) || !wif.chars().all(|c| BASE58.contains(c)) {
+        return Err(Error::Config(
+            "private key is not in wallet import format (WIF)".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TemplateCapability, WalletTxCategory};
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, body_string_contains, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Starts a mock node that answers requests matching `request` with the given result.
+    ///
+    /// The mock expects to be called exactly once, which is verified when the server is dropped.
+    async fn mock_node(request: Value, result: Value) -> (MockServer, RpcClient) {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(request))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": result,
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        (server, client)
+    }
+
+    /// Starts a mock node that answers requests matching `request` with an RPC error.
+    async fn mock_node_error(request: Value, code: i32, message: &str) -> (MockServer, RpcClient) {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(request))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+                "result": null,
+                "error": {"code": code, "message": message},
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        (server, client)
+    }
+
+    /// Starts a mock node that answers requests matching `request` with a result given as raw
+    /// JSON text, for results that `Value` can't represent exactly.
+    async fn mock_node_raw(request: Value, result: &str) -> (MockServer, RpcClient) {
+        let server = MockServer::start().await;
+        let body = format!(
+            r#"{{"result":{},"error":null,"id":"bitcoinsv-rpc"}}"#,
+            result
+        );
+        Mock::given(method("POST"))
+            .and(body_partial_json(request))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        (server, client)
+    }
+
+    #[test]
+    fn test_rpc_client_creation() {
+        let client = RpcClient::new(
+            "http://localhost:8332",
+            Some("user".to_string()),
+            Some("pass".to_string()),
+        );
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_rpc_client_invalid_url() {
+        let client = RpcClient::new("localhost:8332", None, None);
+        assert!(client.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_block_count() {
+        let (_server, client) =
+            mock_node(json!({"method": "getblockcount"}), json!(1_650_000)).await;
+        assert_eq!(client.get_block_count().await.unwrap(), 1_650_000);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_is_surfaced() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "getblockcount"}),
+            -28,
+            "Loading block index...",
+        )
+        .await;
+
+        match client.get_block_count().await {
+            Err(Error::Rpc { code, message }) => {
+                assert_eq!(code, -28);
+                assert_eq!(message, "Loading block index...");
+            }
+            other => panic!("expected RPC error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_null_result() {
+        let (_server, client) = mock_node(json!({"method": "invalidateblock"}), Value::Null).await;
+        let hash =
+            BlockHash::from_hex("00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c")
+                .unwrap();
+        assert!(client.invalidate_block(&hash).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_call_null_result_clear_banned() {
+        let (_server, client) =
+            mock_node(json!({"method": "clearbanned", "params": []}), Value::Null).await;
+        assert!(client.clear_banned().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_call_null_result_ping() {
+        let (_server, client) =
+            mock_node(json!({"method": "ping", "params": []}), Value::Null).await;
+        assert!(client.ping().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_call_null_result_safe_mode_overrides() {
+        let server = MockServer::start().await;
+        for rpc_method in ["ignoresafemodeforblock", "reconsidersafemodeforblock"] {
+            Mock::given(method("POST"))
+                .and(body_partial_json(
+                    json!({"method": rpc_method, "params": [GENESIS_HASH]}),
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "result": null,
+                    "error": null,
+                    "id": "bitcoinsv-rpc"
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        let hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        client.ignore_safe_mode_for_block(&hash).await.unwrap();
+        client.reconsider_safe_mode_for_block(&hash).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_call_null_result_for_optional() {
+        let (_server, client) = mock_node(json!({"method": "getblockcount"}), Value::Null).await;
+        let result: Option<u64> = client.call("getblockcount", vec![]).await.unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[tokio::test]
+    async fn test_call_missing_result() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"error": null, "id": "bitcoinsv-rpc"})),
+            )
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        let result: Result<()> = client.call("invalidateblock", vec![]).await;
+        assert!(matches!(result, Err(Error::Other(_))));
+    }
+
+    #[tokio::test]
+    async fn test_call_null_result_for_value() {
+        let (_server, client) = mock_node(json!({"method": "getblockcount"}), Value::Null).await;
+        assert!(matches!(
+            client.get_block_count().await,
+            Err(Error::Json(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_block_hash_out_of_range() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "getblockhash", "params": [999_999_999]}),
+            -8,
+            "Block height out of range",
+        )
+        .await;
+
+        assert!(matches!(
+            client.get_block_hash(999_999_999).await,
+            Err(Error::BlockHeightOutOfRange(999_999_999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_tx_stats_hash_only() {
+        let hash_str = "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c";
+        let hash = BlockHash::from_hex(hash_str).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "getchaintxstats", "params": [null, hash_str]}),
+            json!({
+                "time": 1724412345,
+                "txcount": 1523456789,
+                "window_final_block_hash": hash_str,
+                "window_block_count": 4320,
+                "window_tx_count": 98765432,
+                "window_interval": 2592000,
+                "txrate": 38.104
+            }),
+        )
+        .await;
+
+        let stats = client.get_chain_tx_stats(None, Some(&hash)).await.unwrap();
+        assert_eq!(stats.tx_count, 1523456789);
+        assert_eq!(stats.window_block_count, 4320);
+        assert_eq!(stats.window_tx_count, Some(98765432));
+        assert_eq!(stats.tx_rate, Some(38.104));
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_tx_stats_no_params() {
+        let (_server, client) = mock_node(
+            json!({"method": "getchaintxstats", "params": []}),
+            json!({"time": 1231006505, "txcount": 1, "window_block_count": 0}),
+        )
+        .await;
+
+        let stats = client.get_chain_tx_stats(None, None).await.unwrap();
+        assert_eq!(stats.window_block_count, 0);
+        assert_eq!(stats.window_interval, None);
+        assert_eq!(stats.tx_rate, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_block_stats_by_height_with_filter() {
+        let (_server, client) = mock_node(
+            json!({"method": "getblockstatsbyheight", "params": [861234, ["txs", "totalfee"]]}),
+            json!({"txs": 42856, "totalfee": 1043210}),
+        )
+        .await;
+
+        let stats = client
+            .get_block_stats(BlockStatsTarget::Height(861234), Some(&["txs", "totalfee"]))
+            .await
+            .unwrap();
+        assert_eq!(stats.txs, Some(42856));
+        assert_eq!(stats.total_fee, Some(1043210));
+        assert_eq!(stats.height, None);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_block_height_timeout() {
+        // The node returns the current tip when the timeout elapses first
+        let (_server, client) = mock_node(
+            json!({"method": "waitforblockheight", "params": [861300, 5000]}),
+            json!({
+                "hash": "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c",
+                "height": 861234
+            }),
+        )
+        .await;
+
+        let tip = client
+            .wait_for_block_height(861300, Some(5000))
+            .await
+            .unwrap();
+        assert_eq!(tip.height, 861234);
+        assert!(tip.height < 861300);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_block() {
+        let hash_str = "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c";
+        let hash = BlockHash::from_hex(hash_str).unwrap();
+        // The hash is sent in display order, the same as it is returned
+        let (_server, client) = mock_node(
+            json!({"method": "waitforblock", "params": [hash_str]}),
+            json!({"hash": hash_str, "height": 861234}),
+        )
+        .await;
+
+        let tip = client.wait_for_block(&hash, None).await.unwrap();
+        assert_eq!(tip.hash, hash);
+    }
+
+    #[tokio::test]
+    async fn test_get_network_hash_ps_at_height() {
+        let (_server, client) = mock_node(
+            json!({"method": "getnetworkhashps", "params": [null, 800000]}),
+            json!(4.123456789e17),
+        )
+        .await;
+
+        let hash_ps = client
+            .get_network_hash_ps(None, Some(800000))
+            .await
+            .unwrap();
+        assert_eq!(hash_ps, 4.123456789e17);
+    }
+
+    /// Hash of the mainnet genesis block.
+    const GENESIS_HASH: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+    /// Id of the coinbase transaction in the genesis block.
+    const GENESIS_COINBASE_TXID: &str =
+        "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+
+    /// The coinbase transaction in the genesis block.
+    const GENESIS_COINBASE_HEX: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+
+    #[tokio::test]
+    async fn test_get_raw_transaction() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "getrawtransaction", "params": [GENESIS_COINBASE_TXID, false]}),
+            json!(GENESIS_COINBASE_HEX),
+        )
+        .await;
+
+        let tx = client.get_raw_transaction(&txid).await.unwrap();
+        assert_eq!(tx.hash(), txid);
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_transaction_not_found() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node_error(
+            json!({"method": "getrawtransaction"}),
+            -5,
+            "No such mempool or blockchain transaction. Use gettransaction for wallet transactions.",
+        )
+        .await;
+
+        match client.get_raw_transaction(&txid).await {
+            Err(Error::NotFound(message)) => assert!(message.contains("-txindex")),
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_transaction_info() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "getrawtransaction", "params": [GENESIS_COINBASE_TXID, true]}),
+            json!({
+                "txid": GENESIS_COINBASE_TXID,
+                "hash": GENESIS_COINBASE_TXID,
+                "version": 1,
+                "size": 204,
+                "locktime": 0,
+                "vin": [{"coinbase": "04ffff001d0104", "sequence": 4294967295u32}],
+                "vout": [{
+                    "value": 50.0,
+                    "n": 0,
+                    "scriptPubKey": {"asm": "OP_CHECKSIG", "hex": "ac", "type": "nonstandard"}
+                }],
+                "hex": GENESIS_COINBASE_HEX,
+                "blockhash": "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f",
+                "confirmations": 800000,
+                "time": 1231006505,
+                "blocktime": 1231006505
+            }),
+        )
+        .await;
+
+        let info = client.get_raw_transaction_info(&txid).await.unwrap();
+        assert_eq!(info.txid, txid);
+        assert!(info.is_confirmed());
+        assert_eq!(info.vout[0].value, 5_000_000_000);
+        assert_eq!(info.hex, GENESIS_COINBASE_HEX);
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_transaction() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "sendrawtransaction",
+                "params": [GENESIS_COINBASE_HEX, false, true]
+            }),
+            json!(GENESIS_COINBASE_TXID),
+        )
+        .await;
+
+        let txid = client
+            .send_raw_transaction(GENESIS_COINBASE_HEX, false, true)
+            .await
+            .unwrap();
+        // The node returns the txid in display order
+        assert_eq!(txid.to_string(), GENESIS_COINBASE_TXID);
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_transaction_rejected() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "sendrawtransaction"}),
+            -25,
+            "Missing inputs",
+        )
+        .await;
+
+        match client
+            .send_raw_transaction(GENESIS_COINBASE_HEX, false, false)
+            .await
+        {
+            Err(Error::Rpc { code, message }) => {
+                assert_eq!(code, -25);
+                assert_eq!(message, "Missing inputs");
+            }
+            other => panic!("expected Rpc error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_transactions() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "sendrawtransactions",
+                "params": [[
+                    {"hex": "0100", "allowhighfees": true},
+                    {"hex": "0200", "dontcheckfee": true, "listunconfirmedancestors": true},
+                    {"hex": "0300"}
+                ]]
+            }),
+            json!({
+                "known": [GENESIS_COINBASE_TXID],
+                "invalid": [{
+                    "txid": "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+                    "reject_code": 16,
+                    "reject_reason": "bad-txns-inputs-missingorspent"
+                }]
+            }),
+        )
+        .await;
+
+        let txs = [
+            RawTxSubmission {
+                allow_high_fees: true,
+                ..RawTxSubmission::new("0100")
+            },
+            RawTxSubmission {
+                dont_check_fee: true,
+                list_unconfirmed_ancestors: true,
+                ..RawTxSubmission::new("0200")
+            },
+            RawTxSubmission::new("0300"),
+        ];
+        let result = client.send_raw_transactions(&txs).await.unwrap();
+        assert_eq!(result.known[0].to_string(), GENESIS_COINBASE_TXID);
+        assert!(result.evicted.is_empty());
+        assert_eq!(result.invalid.len(), 1);
+        assert_eq!(result.invalid[0].reject_code, Some(16));
+        assert_eq!(
+            result.invalid[0].reject_reason,
+            "bad-txns-inputs-missingorspent"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_raw_transaction() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (server, client) = mock_node(
+            json!({
+                "method": "createrawtransaction",
+                "params": [
+                    [{"txid": GENESIS_COINBASE_TXID, "vout": 0, "sequence": 0}],
+                    {"mzFirst": 2_099_999.999_999_99, "mzSecond": 0.000_000_01},
+                    500000
+                ]
+            }),
+            json!("0100"),
+        )
+        .await;
+
+        let inputs = [TxInput {
+            sequence: Some(0),
+            ..TxInput::new(txid, 0)
+        }];
+        let outputs = [
+            ("mzFirst".to_string(), 209_999_999_999_999),
+            ("mzSecond".to_string(), 1),
+        ];
+        let hex = client
+            .create_raw_transaction(&inputs, &outputs, Some(500000))
+            .await
+            .unwrap();
+        assert_eq!(hex, "0100");
+
+        // The amounts are sent as exact decimals, in the given order
+        let requests = server.received_requests().await.unwrap();
+        let body = String::from_utf8(requests[0].body.clone()).unwrap();
+        assert!(body.contains(r#"{"mzFirst":2099999.99999999,"mzSecond":0.00000001}"#));
+    }
+
+    #[tokio::test]
+    async fn test_create_raw_transaction_without_locktime() {
+        let (_server, client) = mock_node(
+            json!({"method": "createrawtransaction", "params": [[], {"mzAddr": 1.0}]}),
+            json!("0100"),
+        )
+        .await;
+
+        let outputs = [("mzAddr".to_string(), 100_000_000)];
+        let hex = client
+            .create_raw_transaction(&[], &outputs, None)
+            .await
+            .unwrap();
+        assert_eq!(hex, "0100");
+    }
+
+    #[tokio::test]
+    async fn test_decode_raw_transaction() {
+        let (_server, client) = mock_node(
+            json!({"method": "decoderawtransaction", "params": [GENESIS_COINBASE_HEX]}),
+            json!({
+                "txid": GENESIS_COINBASE_TXID,
+                "hash": GENESIS_COINBASE_TXID,
+                "version": 1,
+                "size": 204,
+                "locktime": 0,
+                "vin": [{"coinbase": "04ffff001d0104", "sequence": 4294967295u32}],
+                "vout": [{
+                    "value": 50.0,
+                    "n": 0,
+                    "scriptPubKey": {
+                        "asm": "04678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5f OP_CHECKSIG",
+                        "hex": "4104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac",
+                        "reqSigs": 1,
+                        "type": "pubkey",
+                        "addresses": ["1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"]
+                    }
+                }]
+            }),
+        )
+        .await;
+
+        let tx = client
+            .decode_raw_transaction(GENESIS_COINBASE_HEX)
+            .await
+            .unwrap();
+        assert!(tx.vin[0].is_coinbase());
+        assert_eq!(tx.vout[0].value, 5_000_000_000);
+        assert!(tx.vout[0].script_pub_key.asm.ends_with("OP_CHECKSIG"));
+        assert_eq!(
+            tx.vout[0].script_pub_key.addresses,
+            vec!["1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fund_raw_transaction() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "fundrawtransaction",
+                "params": ["0100", {"changePosition": 1, "lockUnspents": true, "feeRate": 0.00000500}]
+            }),
+            json!({"hex": "0200", "fee": 0.00113, "changepos": 1}),
+        )
+        .await;
+
+        let options = FundRawOptions {
+            change_position: Some(1),
+            lock_unspents: Some(true),
+            fee_rate: Some(500),
+            ..FundRawOptions::default()
+        };
+        let funded = client.fund_raw_transaction("0100", &options).await.unwrap();
+        assert_eq!(funded.hex, "0200");
+        assert_eq!(funded.fee, 113_000);
+        assert_eq!(funded.change_position, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_fund_raw_transaction_insufficient_funds() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "fundrawtransaction"}),
+            -4,
+            "Insufficient funds",
+        )
+        .await;
+
+        match client
+            .fund_raw_transaction("0100", &FundRawOptions::default())
+            .await
+        {
+            Err(Error::Rpc { code, message }) => {
+                assert_eq!(code, -4);
+                assert_eq!(message, "Insufficient funds");
+            }
+            other => panic!("expected Rpc error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_tx_out() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "gettxout", "params": [GENESIS_COINBASE_TXID, 0, true]}),
+            json!({
+                "bestblock": "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f",
+                "confirmations": 1,
+                "value": 50.0,
+                "scriptPubKey": {"asm": "OP_CHECKSIG", "hex": "ac", "type": "nonstandard"},
+                "coinbase": true
+            }),
+        )
+        .await;
+
+        let out = client.get_tx_out(&txid, 0, true).await.unwrap().unwrap();
+        assert_eq!(out.value, 5_000_000_000);
+        assert!(out.coinbase);
+    }
+
+    #[tokio::test]
+    async fn test_get_tx_out_spent() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "gettxout", "params": [GENESIS_COINBASE_TXID, 1, false]}),
+            Value::Null,
+        )
+        .await;
+
+        assert_eq!(client.get_tx_out(&txid, 1, false).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_tx_outs() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({
+                "method": "gettxouts",
+                "params": [
+                    [{"txid": GENESIS_COINBASE_TXID, "n": 0}, {"txid": GENESIS_COINBASE_TXID, "n": 1}],
+                    ["value"],
+                    true
+                ]
+            }),
+            json!({"txouts": [{"value": 50.0}, {"error": "missing"}]}),
+        )
+        .await;
+
+        let outs = client
+            .get_tx_outs(&[(txid, 0), (txid, 1)], Some(&["value"]), true)
+            .await
+            .unwrap();
+        assert_eq!(outs.len(), 2);
+        assert_eq!(outs[0].as_ref().unwrap().value, Some(5_000_000_000));
+        assert_eq!(outs[1], None);
+    }
+
+    #[tokio::test]
+    async fn test_get_tx_outs_all_fields() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "gettxouts", "params": [[{"txid": GENESIS_COINBASE_TXID, "n": 0}], ["*"], false]}),
+            json!({"txouts": [{"error": "spent"}]}),
+        )
+        .await;
+
+        let outs = client.get_tx_outs(&[(txid, 0)], None, false).await.unwrap();
+        assert_eq!(outs, vec![None]);
+    }
+
+    #[tokio::test]
+    async fn test_get_tx_out_proof() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let block_hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "gettxoutproof", "params": [[GENESIS_COINBASE_TXID], GENESIS_HASH]}),
+            json!("0100000001"),
+        )
+        .await;
+
+        let proof = client
+            .get_tx_out_proof(&[txid], Some(&block_hash))
+            .await
+            .unwrap();
+        assert_eq!(proof, vec![0x01, 0x00, 0x00, 0x00, 0x01]);
+    }
+
+    #[tokio::test]
+    async fn test_get_tx_out_proof_not_in_block() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node_error(
+            json!({"method": "gettxoutproof", "params": [[GENESIS_COINBASE_TXID]]}),
+            -5,
+            "Not all transactions found in specified or retrieved block",
+        )
+        .await;
+
+        match client.get_tx_out_proof(&[txid], None).await {
+            Err(Error::Rpc { code, .. }) => assert_eq!(code, RPC_INVALID_ADDRESS_OR_KEY),
+            other => panic!("expected Rpc error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_tx_out_proof() {
+        let (_server, client) = mock_node(
+            json!({"method": "verifytxoutproof", "params": ["0100000001"]}),
+            json!([GENESIS_COINBASE_TXID]),
+        )
+        .await;
+
+        let txids = client
+            .verify_tx_out_proof(&[0x01, 0x00, 0x00, 0x00, 0x01])
+            .await
+            .unwrap();
+        assert_eq!(
+            txids,
+            vec![TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_tx_out_proof_tampered() {
+        // The node returns an empty list if the proof doesn't match the merkle root
+        let (_server, client) = mock_node(json!({"method": "verifytxoutproof"}), json!([])).await;
+
+        match client.verify_tx_out_proof(&[0x01]).await {
+            Err(Error::InvalidProof(_)) => {}
+            other => panic!("expected InvalidProof, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_merkle_proof() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "getmerkleproof", "params": [GENESIS_COINBASE_TXID]}),
+            json!({
+                "flags": 0,
+                "index": 0,
+                "txOrId": GENESIS_COINBASE_TXID,
+                "target": GENESIS_HASH,
+                "nodes": []
+            }),
+        )
+        .await;
+
+        let proof = client.get_merkle_proof(&txid, None).await.unwrap();
+        assert_eq!(proof.tx_or_id, GENESIS_COINBASE_TXID);
+        assert!(matches!(
+            proof.target,
+            crate::types::MerkleProofTarget::Hash(_)
+        ));
+        assert!(proof.nodes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_merkle_proof2() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let block_hash = BlockHash::from_hex(GENESIS_HASH).unwrap();
+        let (_server, client) = mock_node(
+            json!({
+                "method": "getmerkleproof2",
+                "params": [GENESIS_HASH, GENESIS_COINBASE_TXID, false, "merkleroot"]
+            }),
+            json!({
+                "index": 0,
+                "txOrId": GENESIS_COINBASE_TXID,
+                "targetType": "merkleRoot",
+                "target": GENESIS_COINBASE_TXID,
+                "nodes": []
+            }),
+        )
+        .await;
+
+        let proof = client
+            .get_merkle_proof2(&block_hash, &txid, false, TargetType::MerkleRoot)
+            .await
+            .unwrap();
+        assert_eq!(proof.target_type, Some(TargetType::MerkleRoot));
+        assert_eq!(proof.index, 0);
+    }
+
+    /// A TSC proof for the genesis coinbase, which is the only transaction in its block.
+    fn genesis_tsc_proof() -> TscMerkleProof {
+        TscMerkleProof {
+            index: 0,
+            tx_or_id: GENESIS_COINBASE_TXID.to_string(),
+            target_type: None,
+            target: GENESIS_HASH.to_string(),
+            nodes: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_merkle_proof() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "verifymerkleproof",
+                "params": [{
+                    "index": 0,
+                    "txOrId": GENESIS_COINBASE_TXID,
+                    "target": GENESIS_HASH,
+                    "nodes": []
+                }]
+            }),
+            json!(true),
+        )
+        .await;
+
+        assert!(client
+            .verify_merkle_proof(&genesis_tsc_proof())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_merkle_proof_malformed() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "verifymerkleproof"}),
+            -8,
+            "txOrId must be hexadecimal string",
+        )
+        .await;
+
+        match client.verify_merkle_proof(&genesis_tsc_proof()).await {
+            Err(Error::InvalidProof(message)) => assert!(message.contains("txOrId")),
+            other => panic!("expected InvalidProof, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_merkle_proof_unknown_block() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "verifymerkleproof"}),
+            -5,
+            "Block not found",
+        )
+        .await;
+
+        match client.verify_merkle_proof(&genesis_tsc_proof()).await {
+            Err(Error::NotFound(message)) => assert_eq!(message, "Block not found"),
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getmempoolinfo", "params": []}),
+            json!({
+                "size": 2,
+                "journalsize": 2,
+                "nonfinalsize": 0,
+                "bytes": 450,
+                "usage": 2176,
+                "maxmempool": 10000000000u64,
+                "mempoolminfee": 0.0
+            }),
+        )
+        .await;
+
+        let info = client.get_mempool_info().await.unwrap();
+        assert_eq!(info.size, 2);
+        assert_eq!(info.journal_size, Some(2));
+        assert_eq!(info.mempool_min_fee, 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_mempool() {
+        let other = "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4";
+        let (_server, client) = mock_node(
+            json!({"method": "getrawmempool", "params": [false]}),
+            json!([GENESIS_COINBASE_TXID, other]),
+        )
+        .await;
+
+        let txids = client.get_raw_mempool().await.unwrap();
+        assert_eq!(
+            txids,
+            vec![
+                TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap(),
+                TxHash::from_hex(other).unwrap()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_mempool_empty() {
+        let (_server, client) = mock_node(json!({"method": "getrawmempool"}), json!([])).await;
+        assert!(client.get_raw_mempool().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_mempool_verbose() {
+        let (_server, client) = mock_node(
+            json!({"method": "getrawmempool", "params": [true]}),
+            json!({
+                GENESIS_COINBASE_TXID: {
+                    "size": 204,
+                    "fee": 0.00001,
+                    "modifiedfee": 0.00001,
+                    "time": 1700000000,
+                    "height": 820000,
+                    "depends": []
+                }
+            }),
+        )
+        .await;
+
+        let entries = client.get_raw_mempool_verbose().await.unwrap();
+        let entry = &entries[&TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap()];
+        assert_eq!(entry.fee, 1_000);
+        assert!(entry.depends.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_entry() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "getmempoolentry", "params": [GENESIS_COINBASE_TXID]}),
+            json!({
+                "size": 204,
+                "fee": 0.0002,
+                "modifiedfee": 0.0002,
+                "time": 1700000000,
+                "height": 820000,
+                "depends": []
+            }),
+        )
+        .await;
+
+        let entry = client.get_mempool_entry(&txid).await.unwrap();
+        assert_eq!(entry.size, 204);
+        assert_eq!(entry.fee, 20_000);
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_entry_not_found() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node_error(
+            json!({"method": "getmempoolentry"}),
+            -5,
+            "Transaction not in mempool",
+        )
+        .await;
+
+        match client.get_mempool_entry(&txid).await {
+            Err(Error::NotFound(message)) => assert!(message.contains(GENESIS_COINBASE_TXID)),
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_ancestors() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let parent = "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4";
+        let (_server, client) = mock_node(
+            json!({"method": "getmempoolancestors", "params": [GENESIS_COINBASE_TXID, false]}),
+            json!([parent]),
+        )
+        .await;
+
+        let ancestors = client.get_mempool_ancestors(&txid).await.unwrap();
+        assert_eq!(ancestors, vec![TxHash::from_hex(parent).unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_ancestors_verbose() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let parent = "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4";
+        let (_server, client) = mock_node(
+            json!({"method": "getmempoolancestors", "params": [GENESIS_COINBASE_TXID, true]}),
+            json!({
+                parent: {
+                    "size": 226,
+                    "fee": 0.0001,
+                    "modifiedfee": 0.0001,
+                    "time": 1700000000,
+                    "height": 820000,
+                    "depends": []
+                }
+            }),
+        )
+        .await;
+
+        let ancestors = client.get_mempool_ancestors_verbose(&txid).await.unwrap();
+        assert_eq!(ancestors.len(), 1);
+        assert_eq!(ancestors[&TxHash::from_hex(parent).unwrap()].fee, 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_ancestors_not_found() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node_error(
+            json!({"method": "getmempoolancestors"}),
+            -5,
+            "Transaction not in mempool",
+        )
+        .await;
+
+        assert!(matches!(
+            client.get_mempool_ancestors(&txid).await,
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_descendants() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let child = "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4";
+        let (_server, client) = mock_node(
+            json!({"method": "getmempooldescendants", "params": [GENESIS_COINBASE_TXID, false]}),
+            json!([child]),
+        )
+        .await;
+
+        let descendants = client.get_mempool_descendants(&txid).await.unwrap();
+        assert_eq!(descendants, vec![TxHash::from_hex(child).unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_descendants_verbose() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let child = "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4";
+        let (_server, client) = mock_node(
+            json!({"method": "getmempooldescendants", "params": [GENESIS_COINBASE_TXID, true]}),
+            json!({
+                child: {
+                    "size": 191,
+                    "fee": 0.0001,
+                    "modifiedfee": 0.0001,
+                    "time": 1700000005,
+                    "height": 820000,
+                    "depends": [GENESIS_COINBASE_TXID]
+                }
+            }),
+        )
+        .await;
+
+        let descendants = client.get_mempool_descendants_verbose(&txid).await.unwrap();
+        let entry = &descendants[&TxHash::from_hex(child).unwrap()];
+        assert_eq!(entry.depends, vec![txid]);
+    }
+
+    #[tokio::test]
+    async fn test_get_mempool_descendants_not_found() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node_error(
+            json!({"method": "getmempooldescendants"}),
+            -5,
+            "Transaction not in mempool",
+        )
+        .await;
+
+        assert!(matches!(
+            client.get_mempool_descendants_verbose(&txid).await,
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_save_mempool() {
+        let (_server, client) =
+            mock_node(json!({"method": "savemempool", "params": []}), Value::Null).await;
+
+        client.save_mempool().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_mempool_in_progress() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "savemempool"}),
+            -1,
+            "Unable to dump mempool to disk",
+        )
+        .await;
+
+        match client.save_mempool().await {
+            Err(Error::Rpc { code, message }) => {
+                assert_eq!(code, -1);
+                assert_eq!(message, "Unable to dump mempool to disk");
+            }
+            other => panic!("expected Rpc error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_journal() {
+        let (_server, client) = mock_node(
+            json!({"method": "checkjournal", "params": []}),
+            json!({"ok": false, "errors": "Journal and mempool sizes differ\n"}),
+        )
+        .await;
+
+        let result = client.check_journal().await.unwrap();
+        assert!(!result.ok);
+        assert_eq!(result.errors, "Journal and mempool sizes differ\n");
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_journal() {
+        let (_server, client) = mock_node(
+            json!({"method": "rebuildjournal", "params": []}),
+            Value::Null,
+        )
+        .await;
+
+        client.rebuild_journal().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_mining_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getmininginfo", "params": []}),
+            json!({
+                "blocks": 101,
+                "currentblocksize": 0,
+                "currentblocktx": 0,
+                "difficulty": 4.656542373906925e-10,
+                "networkhashps": 7.2,
+                "pooledtx": 0,
+                "chain": "regtest"
+            }),
+        )
+        .await;
+
+        let info = client.get_mining_info().await.unwrap();
+        assert_eq!(info.blocks, 101);
+        assert_eq!(info.chain, crate::types::Network::Regtest);
+    }
+
+    #[tokio::test]
+    async fn test_get_mining_candidate() {
+        let (_server, client) = mock_node(
+            json!({"method": "getminingcandidate", "params": [true]}),
+            json!({
+                "id": "a5c6ef4b-2b0b-4ff3-8fbd-df7e4a7b1b2c",
+                "prevhash": GENESIS_HASH,
+                "coinbase": GENESIS_COINBASE_HEX,
+                "coinbaseValue": 5000000000u64,
+                "version": 1,
+                "nBits": "1d00ffff",
+                "time": 1231469665,
+                "height": 1,
+                "num_tx": 1,
+                "sizeWithoutCoinbase": 80,
+                "merkleProof": []
+            }),
+        )
+        .await;
+
+        let candidate = client.get_mining_candidate(true).await.unwrap();
+        assert_eq!(
+            candidate.prev_hash,
+            BlockHash::from_hex(GENESIS_HASH).unwrap()
+        );
+        assert_eq!(candidate.coinbase.as_deref(), Some(GENESIS_COINBASE_HEX));
+        assert_eq!(candidate.bits, 0x1d00ffff);
+        assert!(candidate.merkle_proof.is_empty());
+    }
+
+    fn test_solution() -> MiningSolution {
+        MiningSolution {
+            id: "a5c6ef4b".to_string(),
+            nonce: 42,
+            coinbase: None,
+            time: Some(1700000000),
+            version: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_mining_solution() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "submitminingsolution",
+                "params": [{"id": "a5c6ef4b", "nonce": 42, "time": 1700000000}]
+            }),
+            json!(true),
+        )
+        .await;
+
+        client
+            .submit_mining_solution(&test_solution())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_submit_mining_solution_rejected() {
+        let (_server, client) = mock_node(
+            json!({"method": "submitminingsolution"}),
+            json!("high-hash"),
+        )
+        .await;
+
+        match client.submit_mining_solution(&test_solution()).await {
+            Err(Error::BlockRejected(reason)) => assert_eq!(reason, "high-hash"),
+            other => panic!("expected BlockRejected, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_block_template() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "getblocktemplate",
+                "params": [{"capabilities": ["coinbasetxn"]}]
+            }),
+            json!({
+                "version": 536870912,
+                "previousblockhash": GENESIS_HASH,
+                "transactions": [],
+                "coinbasevalue": 5000000000u64,
+                "target": "00000000ffff0000000000000000000000000000000000000000000000000000",
+                "mintime": 1231006506,
+                "noncerange": "00000000ffffffff",
+                "curtime": 1231006600,
+                "bits": "1d00ffff",
+                "height": 1
+            }),
+        )
+        .await;
+
+        let request = BlockTemplateRequest {
+            capabilities: vec![TemplateCapability::CoinbaseTxn],
+            ..Default::default()
+        };
+        let template = client.get_block_template(Some(&request)).await.unwrap();
+        assert_eq!(template.height, 1);
+        assert_eq!(template.bits, 0x1d00ffff);
+        assert!(template.transactions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_submit_block_accepted() {
+        let (_server, client) = mock_node(
+            json!({"method": "submitblock", "params": ["0100"]}),
+            Value::Null,
+        )
+        .await;
+
+        assert_eq!(client.submit_block("0100").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_submit_block_duplicate() {
+        let (_server, client) =
+            mock_node(json!({"method": "submitblock"}), json!("duplicate")).await;
+
+        assert_eq!(
+            client.submit_block("0100").await.unwrap(),
+            Some("duplicate".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_to_address() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "generatetoaddress",
+                "params": [1, "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"]
+            }),
+            json!([GENESIS_HASH]),
+        )
+        .await;
+
+        let hashes = client
+            .generate_to_address(1, "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", None)
+            .await
+            .unwrap();
+        assert_eq!(hashes, vec![BlockHash::from_hex(GENESIS_HASH).unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_generate() {
+        let (_server, client) = mock_node(
+            json!({"method": "generate", "params": [1, 1000]}),
+            json!([GENESIS_HASH]),
+        )
+        .await;
+
+        let hashes = client.generate(1, Some(1000)).await.unwrap();
+        assert_eq!(hashes, vec![BlockHash::from_hex(GENESIS_HASH).unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_generate_removed() {
+        let (_server, client) =
+            mock_node_error(json!({"method": "generate"}), -32601, "Method not found").await;
+
+        match client.generate(1, None).await {
+            Err(Error::UnsupportedMethod(msg)) => {
+                // The node gives the same error when it has no wallet
+                assert!(msg.contains("wallet disabled"));
+                assert!(msg.contains("generate_to_address"));
+            }
+            other => panic!("expected UnsupportedMethod, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_estimate_fee() {
+        let (_server, client) = mock_node(
+            json!({"method": "estimatefee", "params": [6]}),
+            json!(0.0005),
+        )
+        .await;
+
+        let rate = client.estimate_fee(Some(6)).await.unwrap();
+        assert_eq!(rate.sats_per_kb(), 50_000);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_fee_unavailable() {
+        let (_server, client) =
+            mock_node(json!({"method": "estimatefee", "params": []}), json!(-1)).await;
+
+        assert!(matches!(
+            client.estimate_fee(None).await,
+            Err(Error::NoFeeEstimate)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_peer_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getpeerinfo"}),
+            json!([{
+                "id": 1,
+                "addr": "abcdefghijklmnop.onion:8333",
+                "services": "0000000000000025",
+                "lastsend": 1700000100,
+                "lastrecv": 1700000101,
+                "bytessent": 1000,
+                "bytesrecv": 2000,
+                "conntime": 1699990000,
+                "version": 70016,
+                "subver": "/Bitcoin SV:1.0.16/",
+                "inbound": false,
+                "startingheight": 820000,
+                "banscore": 0,
+                "synced_headers": 820005,
+                "synced_blocks": 820004,
+                "whitelisted": true
+            }]),
+        )
+        .await;
+
+        let peers = client.get_peer_info().await.unwrap();
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].addr, "abcdefghijklmnop.onion:8333");
+        assert!(peers[0].whitelisted);
+    }
+
+    #[tokio::test]
+    async fn test_get_connection_count() {
+        let (_server, client) = mock_node(
+            json!({"method": "getconnectioncount", "params": []}),
+            json!(8),
+        )
+        .await;
+        assert_eq!(client.get_connection_count().await.unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_credentials_sent_as_basic_auth() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            // "user:pass" in base64
+            .and(header("authorization", "Basic dXNlcjpwYXNz"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": 8,
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(
+            &server.uri(),
+            Some("user".to_string()),
+            Some("pass".to_string()),
+        )
+        .unwrap();
+        assert_eq!(client.get_connection_count().await.unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_get_net_totals() {
+        let (_server, client) = mock_node(
+            json!({"method": "getnettotals"}),
+            json!({
+                "totalbytesrecv": 1000,
+                "totalbytessent": 2000,
+                "timemillis": 1700000000123u64
+            }),
+        )
+        .await;
+
+        let totals = client.get_net_totals().await.unwrap();
+        assert_eq!(totals.total_bytes_sent, 2000);
+        assert_eq!(totals.upload_target, None);
+    }
+
+    #[tokio::test]
+    async fn test_add_node() {
+        let (_server, client) = mock_node(
+            json!({"method": "addnode", "params": ["203.0.113.7:8333", "onetry"]}),
+            Value::Null,
+        )
+        .await;
+
+        client
+            .add_node("203.0.113.7:8333", AddNodeCommand::OneTry)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_add_node_already_added() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "addnode", "params": ["203.0.113.7:8333", "add"]}),
+            -23,
+            "Error: Node already added",
+        )
+        .await;
+
+        match client
+            .add_node("203.0.113.7:8333", AddNodeCommand::Add)
+            .await
+        {
+            Err(Error::NodeAlreadyAdded(addr)) => assert_eq!(addr, "203.0.113.7:8333"),
+            other => panic!("expected NodeAlreadyAdded, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_node_not_added() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "addnode", "params": ["203.0.113.7:8333", "remove"]}),
+            -24,
+            "Error: Node has not been added.",
+        )
+        .await;
+
+        assert!(matches!(
+            client
+                .add_node("203.0.113.7:8333", AddNodeCommand::Remove)
+                .await,
+            Err(Error::NodeNotAdded(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_node_by_address() {
+        let (_server, client) = mock_node(
+            json!({"method": "disconnectnode", "params": ["203.0.113.7:8333"]}),
+            Value::Null,
+        )
+        .await;
+
+        client
+            .disconnect_node(&DisconnectTarget::Address("203.0.113.7:8333".to_string()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_node_by_id() {
+        let (_server, client) = mock_node(
+            json!({"method": "disconnectnode", "params": ["", 7]}),
+            Value::Null,
+        )
+        .await;
+
+        client
+            .disconnect_node(&DisconnectTarget::NodeId(7))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_node_unknown() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "disconnectnode"}),
+            -29,
+            "Node not found in connected nodes",
+        )
+        .await;
+
+        match client.disconnect_node(&DisconnectTarget::NodeId(99)).await {
+            Err(Error::Rpc { code, .. }) => assert_eq!(code, -29),
+            other => panic!("expected an RPC error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_added_node_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getaddednodeinfo", "params": []}),
+            json!([{
+                "addednode": "203.0.113.7:8333",
+                "connected": true,
+                "addresses": [{"address": "203.0.113.7:8333", "connected": "inbound"}]
+            }]),
+        )
+        .await;
+
+        let nodes = client.get_added_node_info(None).await.unwrap();
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].added_node, "203.0.113.7:8333");
+    }
+
+    #[tokio::test]
+    async fn test_get_added_node_info_not_added() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "getaddednodeinfo", "params": ["198.51.100.9:8333"]}),
+            -24,
+            "Error: Node has not been added.",
+        )
+        .await;
+
+        match client.get_added_node_info(Some("198.51.100.9:8333")).await {
+            Err(Error::NodeNotAdded(node)) => assert_eq!(node, "198.51.100.9:8333"),
+            other => panic!("expected NodeNotAdded, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_ban() {
+        let (_server, client) = mock_node(
+            json!({"method": "setban", "params": ["192.0.2.0/24", "add", 86400]}),
+            Value::Null,
+        )
+        .await;
+
+        client
+            .set_ban("192.0.2.0/24", BanCommand::Add, Some(86400), false)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_ban_already_banned() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "setban", "params": ["192.0.2.1", "add"]}),
+            -23,
+            "Error: IP/Subnet already banned",
+        )
+        .await;
+
+        match client
+            .set_ban("192.0.2.1", BanCommand::Add, None, false)
+            .await
+        {
+            Err(Error::Rpc { code, message }) => {
+                assert_eq!(code, -23);
+                assert_eq!(message, "Error: IP/Subnet already banned");
+            }
+            other => panic!("expected an RPC error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_ban_invalid_subnet() {
+        // No request is made, so the client doesn't need a server
+        let client = RpcClient::new("http://localhost:1", None, None).unwrap();
+        assert!(matches!(
+            client
+                .set_ban("not-an-ip", BanCommand::Add, None, false)
+                .await,
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_priv_key() {
+        let wif = "cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy";
+        let (_server, client) = mock_node(
+            json!({"method": "importprivkey", "params": [wif, "harness", false]}),
+            Value::Null,
+        )
+        .await;
+
+        client
+            .import_priv_key(wif, Some("harness"), false)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dump_priv_key() {
+        let wif = "cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy";
+        let (_server, client) = mock_node(
+            json!({"method": "dumpprivkey", "params": ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"]}),
+            json!(wif),
+        )
+        .await;
+
+        let key = client
+            .dump_priv_key("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn")
+            .await
+            .unwrap();
+        assert_eq!(key, wif);
+    }
+
+    #[tokio::test]
+    async fn test_dump_priv_key_wallet_locked() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "dumpprivkey"}),
+            -13,
+            "Error: Please enter the wallet passphrase with walletpassphrase first.",
+        )
+        .await;
+
+        let result = client
+            .dump_priv_key("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn")
+            .await;
+        assert!(matches!(result, Err(Error::WalletLocked)));
+    }
+
+    #[tokio::test]
+    async fn test_get_wallet_info_no_wallet() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "getwalletinfo"}),
+            -32601,
+            "Method not found",
+        )
+        .await;
+
+        let result = client.get_wallet_info().await;
+        assert!(matches!(result, Err(Error::WalletUnavailable)));
+    }
+
+    #[tokio::test]
+    async fn test_backup_wallet() {
+        let (_server, client) = mock_node(
+            json!({"method": "backupwallet", "params": ["/backups/wallet.dat"]}),
+            Value::Null,
+        )
+        .await;
+
+        client.backup_wallet("/backups/wallet.dat").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_backup_wallet_denied() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "backupwallet"}),
+            -4,
+            "Error: Wallet backup failed!",
+        )
+        .await;
+
+        let result = client.backup_wallet("/root/wallet.dat").await;
+        assert!(matches!(result, Err(Error::FileAccessDenied(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dump_wallet() {
+        let (_server, client) = mock_node(
+            json!({"method": "dumpwallet", "params": ["keys.txt"]}),
+            json!({"filename": "/home/node/.bitcoin/keys.txt"}),
+        )
+        .await;
+
+        let path = client.dump_wallet("keys.txt").await.unwrap();
+        assert_eq!(path, "/home/node/.bitcoin/keys.txt");
+    }
+
+    #[tokio::test]
+    async fn test_dump_wallet_errors() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "dumpwallet"}),
+            -8,
+            "Cannot open wallet dump file",
+        )
+        .await;
+        let result = client.dump_wallet("/root/keys.txt").await;
+        assert!(matches!(result, Err(Error::FileAccessDenied(_))));
+
+        // Other invalid parameters keep their code
+        let (_server, client) = mock_node_error(
+            json!({"method": "dumpwallet"}),
+            -8,
+            "/home/node/keys.txt already exists. If you are sure this is what you want, move it out of the way first",
+        )
+        .await;
+        let result = client.dump_wallet("keys.txt").await;
+        assert!(matches!(result, Err(Error::Rpc { code: -8, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_import_address() {
+        let (_server, client) = mock_node(
+            json!({"method": "importaddress", "params": ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"]}),
+            Value::Null,
+        )
+        .await;
+
+        client
+            .import_address("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", None, true, false)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_import_address_p2sh_script() {
+        let script = "5121030b9dc5d7c4a06b8fb2acb1be4ff9d8c0ea9e1a1f9f01e41c6d5b2e8e3a6a5b1f51ae";
+        let (_server, client) = mock_node(
+            json!({"method": "importaddress", "params": [script, "multisig", false, true]}),
+            Value::Null,
+        )
+        .await;
+
+        client
+            .import_address(script, Some("multisig"), false, true)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_import_address_p2sh_with_address() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "importaddress"}),
+            -5,
+            "Cannot use the p2sh flag with an address - use a script instead",
+        )
+        .await;
+
+        let result = client
+            .import_address("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", None, true, true)
+            .await;
+        // The node's message explains the fix, so it is passed through unchanged
+        match result {
+            Err(Error::Rpc { code: -5, message }) => assert!(message.contains("use a script")),
+            other => panic!("expected an RPC error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_wif() {
+        for wif in [
+            "cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy",
+            "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ",
+        ] {
+            assert!(validate_wif(wif).is_ok(), "{}", wif);
+        }
+        for wif in [
+            "",
+            "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+            // 0, O, I and l are not base58
+            "cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFp0",
+            "cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy\n",
+        ] {
+            match validate_wif(wif) {
+                Err(Error::Config(message)) => assert!(!message.contains(wif) || wif.is_empty()),
+                other => panic!("expected Config error for {:?}, got {:?}", wif, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_subnet() {
+        for subnet in [
+            "192.0.2.1",
+            "192.0.2.0/24",
+            "192.0.2.0/255.255.255.0",
+            "2001:db8::1",
+            "2001:db8::/32",
+        ] {
+            assert!(validate_subnet(subnet).is_ok(), "{}", subnet);
+        }
+        for subnet in [
+            "",
+            "garbage",
+            "192.0.2.256",
+            "192.0.2.0/33",
+            "192.0.2.0/",
+            "2001:db8::/129",
+            "192.0.2.0/ffff::",
+        ] {
+            assert!(validate_subnet(subnet).is_err(), "{}", subnet);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_banned() {
+        let (_server, client) = mock_node(
+            json!({"method": "listbanned"}),
+            json!([{
+                "address": "192.0.2.1/32",
+                "banned_until": 1700086400,
+                "ban_created": 1700000000,
+                "ban_reason": "manually added"
+            }]),
+        )
+        .await;
+
+        let banned = client.list_banned().await.unwrap();
+        assert_eq!(banned.len(), 1);
+        assert_eq!(banned[0].banned_until, 1700086400);
+    }
+
+    #[tokio::test]
+    async fn test_set_network_active() {
+        let (_server, client) = mock_node(
+            json!({"method": "setnetworkactive", "params": [false]}),
+            json!(false),
+        )
+        .await;
+        assert!(!client.set_network_active(false).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_measure_latency() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "getbestblockhash"})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({
+                        "result": GENESIS_HASH,
+                        "error": null,
+                        "id": "bitcoinsv-rpc"
+                    }))
+                    .set_delay(Duration::from_millis(50)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        assert!(client.measure_latency().await.unwrap() >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_get_memory_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getmemoryinfo", "params": ["stats"]}),
+            json!({"locked": {
+                "used": 0,
+                "free": 65536,
+                "total": 65536,
+                "locked": 65536,
+                "chunks_used": 0,
+                "chunks_free": 1
+            }}),
+        )
+        .await;
+
+        let info = client.get_memory_info().await.unwrap();
+        assert_eq!(info.locked.free, 65536);
+    }
+
+    #[tokio::test]
+    async fn test_get_malloc_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getmemoryinfo", "params": ["mallocinfo"]}),
+            json!("<malloc version=\"1\"></malloc>"),
+        )
+        .await;
+
+        let xml = client.get_malloc_info().await.unwrap();
+        assert!(xml.starts_with("<malloc"));
+    }
+
+    #[tokio::test]
+    async fn test_get_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getinfo"}),
+            json!({"version": 101001600, "blocks": 820000, "connections": 8}),
+        )
+        .await;
+
+        let info = client.get_info().await.unwrap();
+        assert_eq!(info.blocks, Some(820000));
+        assert_eq!(info.max_block_size, None);
+    }
+
+    #[tokio::test]
+    async fn test_stop() {
+        let (_server, client) = mock_node(
+            json!({"method": "stop", "params": []}),
+            json!("Bitcoin server stopping"),
+        )
+        .await;
+        assert_eq!(client.stop().await.unwrap(), "Bitcoin server stopping");
+    }
+
+    #[tokio::test]
+    async fn test_stop_connection_closed_mid_response() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // A node that sends a success status and then closes the connection mid-body
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n{\"result\":")
+                .await;
+        });
+
+        let client = RpcClient::new(&format!("http://{}", addr), None, None).unwrap();
+        assert_eq!(client.stop().await.unwrap(), "Bitcoin server stopping");
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_wallet_connection_closed() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // The node shuts down after encrypting, possibly before finishing its response
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n{\"result\":")
+                .await;
+        });
+
+        let client = RpcClient::new(&format!("http://{}", addr), None, None).unwrap();
+        let message = client.encrypt_wallet("correct horse").await.unwrap();
+        assert!(message.starts_with("wallet encrypted"));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_passphrase() {
+        let (_server, client) = mock_node(
+            json!({"method": "walletpassphrase", "params": ["correct horse", 60]}),
+            Value::Null,
+        )
+        .await;
+
+        client.wallet_passphrase("correct horse", 60).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_wallet_passphrase_incorrect() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "walletpassphrasechange"}),
+            -14,
+            "Error: The wallet passphrase entered was incorrect.",
+        )
+        .await;
+
+        let error = client
+            .wallet_passphrase_change("correct horse", "battery staple")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Rpc { code: -14, .. }));
+        let shown = format!("{} {:?}", error, error);
+        assert!(!shown.contains("correct horse") && !shown.contains("battery staple"));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_lock() {
+        let (_server, client) =
+            mock_node(json!({"method": "walletlock", "params": []}), Value::Null).await;
+
+        client.wallet_lock().await.unwrap();
+    }
+
+    #[test]
+    fn test_passphrase_debug_is_redacted() {
+        let params = (Passphrase("correct horse"), 60);
+        assert!(!format!("{:?}", params).contains("correct horse"));
+        assert_eq!(
+            serde_json::to_string(&params).unwrap(),
+            r#"["correct horse",60]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_message() {
+        let signature =
+            "IFxDEZOukVob6Vk7VfI+0Ql4+u3OH9yb7Q37QBpHtIfkO/r4UldGL0zUB2vzRLJ6iFv9hK6Zf5Y9kgwjSltfft4=";
+        let (_server, client) = mock_node(
+            json!({"method": "signmessage", "params": ["mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL", "hello"]}),
+            json!(signature),
+        )
+        .await;
+
+        let result = client
+            .sign_message("mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL", "hello")
+            .await
+            .unwrap();
+        assert_eq!(result, signature);
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_errors() {
+        for (code, message) in [
+            (-4, "Private key not available"),
+            (-3, "Address does not refer to key"),
+        ] {
+            let (_server, client) =
+                mock_node_error(json!({"method": "signmessage"}), code, message).await;
+            let result = client
+                .sign_message("mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL", "hello")
+                .await;
+            assert!(matches!(result, Err(Error::KeyNotInWallet(_))), "{}", code);
+        }
+
+        let (_server, client) = mock_node_error(
+            json!({"method": "signmessage"}),
+            -13,
+            "Error: Please enter the wallet passphrase with walletpassphrase first.",
+        )
+        .await;
+        let result = client
+            .sign_message("mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL", "hello")
+            .await;
+        assert!(matches!(result, Err(Error::WalletLocked)));
+    }
+
+    #[tokio::test]
+    async fn test_list_received_by_address() {
+        let (_server, client) = mock_node_raw(
+            json!({"method": "listreceivedbyaddress", "params": [0, true, false]}),
+            r#"[{
+                "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                "amount": 21000000.00000001,
+                "confirmations": 0,
+                "label": "",
+                "txids": ["4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"]
+            }]"#,
+        )
+        .await;
+
+        let received = client
+            .list_received_by_address(Some(0), true, false)
+            .await
+            .unwrap();
+        assert_eq!(received[0].amount, 2_100_000_000_000_001);
+        assert_eq!(received[0].txids[0].to_string(), GENESIS_COINBASE_TXID);
+    }
+
+    #[tokio::test]
+    async fn test_get_received_by_address() {
+        let (_server, client) = mock_node_raw(
+            json!({"method": "getreceivedbyaddress", "params": ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", 6]}),
+            "0.00000001",
+        )
+        .await;
+
+        let received = client
+            .get_received_by_address("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", Some(6))
+            .await
+            .unwrap();
+        assert_eq!(received, 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_multisig_address() {
+        let keys = [
+            "03a0434d9e47f3c86235477c7b1ae6ae5d3442d49b1943c2b752a68e2a47e247c7".to_string(),
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5".to_string(),
+        ];
+        let (_server, client) = mock_node(
+            json!({"method": "addmultisigaddress", "params": [1, keys, "escrow"]}),
+            json!("2N2JD6wb56AfK4tfmM6PwdVmoYk2dCKf4Br"),
+        )
+        .await;
+
+        let address = client
+            .add_multisig_address(1, &keys, Some("escrow"))
+            .await
+            .unwrap();
+        assert_eq!(address, "2N2JD6wb56AfK4tfmM6PwdVmoYk2dCKf4Br");
+    }
+
+    #[tokio::test]
+    async fn test_add_multisig_address_invalid_required() {
+        // No request is made, so the client doesn't need a server
+        let client = RpcClient::new("http://localhost:1", None, None).unwrap();
+        let keys =
+            ["03a0434d9e47f3c86235477c7b1ae6ae5d3442d49b1943c2b752a68e2a47e247c7".to_string()];
+        for required in [0, 2] {
+            let result = client.add_multisig_address(required, &keys, None).await;
+            assert!(matches!(result, Err(Error::Config(_))), "{}", required);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_key_pool_refill() {
+        let (_server, client) = mock_node(
+            json!({"method": "keypoolrefill", "params": [500]}),
+            Value::Null,
+        )
+        .await;
+
+        client.key_pool_refill(Some(500)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_key_pool_refill_wallet_locked() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "keypoolrefill", "params": []}),
+            -13,
+            "Error: Please enter the wallet passphrase with walletpassphrase first.",
+        )
+        .await;
+
+        let result = client.key_pool_refill(None).await;
+        assert!(matches!(result, Err(Error::WalletLocked)));
+    }
+
+    #[tokio::test]
+    async fn test_abandon_transaction() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "abandontransaction", "params": [GENESIS_COINBASE_TXID]}),
+            Value::Null,
+        )
+        .await;
+
+        client.abandon_transaction(&txid).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_abandon_transaction_errors() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        // Both refusals use -5, so only the message tells them apart
+        let (_server, client) = mock_node_error(
+            json!({"method": "abandontransaction"}),
+            -5,
+            "Transaction not eligible for abandonment",
+        )
+        .await;
+        let not_eligible = client.abandon_transaction(&txid).await;
+        assert!(
+            matches!(&not_eligible, Err(Error::Rpc { code: -5, message }) if message.contains("not eligible")),
+            "{:?}",
+            not_eligible
+        );
+
+        let (_server, client) = mock_node_error(
+            json!({"method": "abandontransaction"}),
+            -5,
+            "Invalid or non-wallet transaction id",
+        )
+        .await;
+        let unknown = client.abandon_transaction(&txid).await;
+        assert!(
+            matches!(&unknown, Err(Error::NotFound(item)) if item.contains(GENESIS_COINBASE_TXID)),
+            "{:?}",
+            unknown
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lock_unspent() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "lockunspent", "params": [false, [{"txid": GENESIS_COINBASE_TXID, "vout": 1}]]}),
+            json!(true),
+        )
+        .await;
+
+        assert!(client.lock_unspent(false, &[(txid, 1)]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_list_lock_unspent() {
+        let (_server, client) = mock_node(
+            json!({"method": "listlockunspent", "params": []}),
+            json!([{"txid": GENESIS_COINBASE_TXID, "vout": 3}]),
+        )
+        .await;
+
+        let locked = client.list_lock_unspent().await.unwrap();
+        assert_eq!(locked.len(), 1);
+        assert_eq!(locked[0].0.to_string(), GENESIS_COINBASE_TXID);
+        assert_eq!(locked[0].1, 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_address_groupings() {
+        let (_server, client) = mock_node_raw(
+            json!({"method": "listaddressgroupings", "params": []}),
+            r#"[[["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", 0.00000001, "label"], ["mzChange", 1.5]]]"#,
+        )
+        .await;
+
+        let groups = client.list_address_groupings().await.unwrap();
+        assert_eq!(groups[0].len(), 2);
+        assert_eq!(groups[0][0].amount, 1);
+        assert_eq!(groups[0][1].amount, 150_000_000);
+        assert_eq!(groups[0][1].label, None);
+    }
+
+    #[test]
+    fn test_with_wallet_url() {
+        let client = RpcClient::new("http://127.0.0.1:8332", None, None).unwrap();
+        assert_eq!(client.wallet_url().as_str(), "http://127.0.0.1:8332/");
+
+        let wallet = client.with_wallet("my wallet/2024");
+        assert_eq!(
+            wallet.wallet_url().as_str(),
+            "http://127.0.0.1:8332/wallet/my%20wallet%2F2024"
+        );
+        assert_eq!(wallet.url.as_str(), "http://127.0.0.1:8332/");
+    }
+
+    #[tokio::test]
+    async fn test_with_wallet_routing() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/wallet/cold%20storage"))
+            .and(body_partial_json(json!({"method": "getbalance"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": 0.5,
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({"method": "getblockcount"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": 100,
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None)
+            .unwrap()
+            .with_wallet("cold storage");
+        assert_eq!(client.get_balance(None, false).await.unwrap(), 50_000_000);
+        assert_eq!(client.get_block_count().await.unwrap(), 100);
+    }
+
+    #[tokio::test]
+    async fn test_with_wallet_routing_generate() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/wallet/miner"))
+            .and(body_partial_json(
+                json!({"method": "generate", "params": [1]}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": [GENESIS_HASH],
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None)
+            .unwrap()
+            .with_wallet("miner");
+        let hashes = client.generate(1, None).await.unwrap();
+        assert_eq!(hashes, vec![BlockHash::from_hex(GENESIS_HASH).unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_list_wallets() {
+        let (_server, client) = mock_node(
+            json!({"method": "listwallets", "params": []}),
+            json!(["", "cold storage"]),
+        )
+        .await;
+
+        let wallets = client.list_wallets().await.unwrap();
+        assert_eq!(wallets, vec!["".to_string(), "cold storage".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_stop_error() {
+        let (_server, client) =
+            mock_node_error(json!({"method": "stop"}), -32601, "Method not found").await;
+        assert!(matches!(
+            client.stop().await,
+            Err(Error::Rpc { code: -32601, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_script() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "verifyscript",
+                "params": [[{"tx": "0100", "n": 0}, {"tx": "0200", "n": 1}], true, 5000]
+            }),
+            json!([
+                {"result": "error", "description": "Script evaluation failed"},
+                {"result": "skipped"}
+            ]),
+        )
+        .await;
+
+        let checks = [ScriptCheck::new("0100", 0), ScriptCheck::new("0200", 1)];
+        let results = client.verify_script(&checks, true, Some(5)).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(!results[0].is_ok());
+        assert_eq!(results[1].result, crate::types::ScriptCheckStatus::Skipped);
+    }
+
+    #[tokio::test]
+    async fn test_get_safe_mode_info() {
+        let (_server, client) = mock_node(
+            json!({"method": "getsafemodeinfo"}),
+            json!({
+                "safemodeenabled": false,
+                "activetip": {"hash": GENESIS_HASH, "height": 0, "status": "active"},
+                "forks": []
+            }),
+        )
+        .await;
+
+        let info = client.get_safe_mode_info().await.unwrap();
+        assert!(!info.safe_mode_enabled);
+        assert_eq!(info.reorg, None);
+        assert!(info.forks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_to_policy_blacklist() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({
+                "method": "addToPolicyBlacklist",
+                "params": [{"funds": [{"txOut": {"txId": GENESIS_COINBASE_TXID, "vout": 0}}]}]
+            }),
+            json!({"notProcessed": []}),
+        )
+        .await;
+
+        let result = client
+            .add_to_policy_blacklist(&[FrozenTxo::new(txid, 0)])
+            .await
+            .unwrap();
+        assert!(result.all_processed());
+    }
+
+    #[tokio::test]
+    async fn test_add_to_consensus_blacklist_not_processed() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({
+                "method": "addToConsensusBlacklist",
+                "params": [{"funds": [{
+                    "txOut": {"txId": GENESIS_COINBASE_TXID, "vout": 0},
+                    "enforceAtHeight": [{"start": 10}],
+                    "policyExpiresWithConsensus": false
+                }]}]
+            }),
+            json!({"notProcessed": [{
+                "txOut": {"txId": GENESIS_COINBASE_TXID, "vout": 0},
+                "reason": "Invalid interval"
+            }]}),
+        )
+        .await;
+
+        let txo = FrozenTxo {
+            enforce_at_height: Some(vec![crate::types::HeightInterval {
+                start: 10,
+                stop: None,
+            }]),
+            policy_expires_with_consensus: Some(false),
+            ..FrozenTxo::new(txid, 0)
+        };
+        let result = client.add_to_consensus_blacklist(&[txo]).await.unwrap();
+        assert_eq!(result.not_processed.len(), 1);
+        assert_eq!(result.not_processed[0].txid, txid);
+        assert_eq!(result.not_processed[0].reason, "Invalid interval");
+    }
+
+    #[tokio::test]
+    async fn test_query_blacklist() {
+        let (_server, client) = mock_node(
+            json!({"method": "queryBlacklist"}),
+            json!({"funds": [{
+                "txOut": {"txId": GENESIS_COINBASE_TXID, "vout": 0},
+                "blacklist": ["policy"]
+            }]}),
+        )
+        .await;
+
+        let entries = client.query_blacklist().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].txo.vout, 0);
+    }
+
+    #[tokio::test]
+    async fn test_clear_blacklists() {
+        let (_server, client) = mock_node(
+            json!({"method": "clearBlacklists", "params": [{"removeAllEntries": true}]}),
+            json!({"numRemovedEntries": 4}),
+        )
+        .await;
+
+        assert_eq!(client.clear_blacklists(true, None).await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_validate_address_invalid() {
+        let (_server, client) = mock_node(
+            json!({"method": "validateaddress", "params": ["not-an-address"]}),
+            json!({"isvalid": false}),
+        )
+        .await;
+
+        let validation = client.validate_address("not-an-address").await.unwrap();
+        assert!(!validation.is_valid);
+    }
+
+    #[tokio::test]
+    async fn test_verify_message() {
+        let (_server, client) = mock_node(
+            json!({
+                "method": "verifymessage",
+                "params": ["mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL", "IFxD", "hello"]
+            }),
+            json!(true),
+        )
+        .await;
+
+        assert!(client
+            .verify_message("mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL", "IFxD", "hello")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_message_malformed_signature() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "verifymessage"}),
+            -5,
+            "Malformed base64 encoding",
+        )
+        .await;
+
+        assert!(!client
+            .verify_message("mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL", "!!!", "hello")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_message_invalid_address() {
+        let (_server, client) =
+            mock_node_error(json!({"method": "verifymessage"}), -3, "Invalid address").await;
+
+        assert!(matches!(
+            client.verify_message("garbage", "IFxD", "hello").await,
+            Err(Error::Rpc { code: -3, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_multisig() {
+        let keys = vec![
+            "02a7bd8a5fe8d8ab6ad4e2e95aa1b0b39b7b4fbac2b0bd4d1c1bdc0b0e3f5a7c8d".to_string(),
+            "03b1c4a1c9f3e4d5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0".to_string(),
+        ];
+        let (_server, client) = mock_node(
+            json!({"method": "createmultisig", "params": [2, keys]}),
+            json!({
+                "address": "2N4Q5FhU2497BryFfUgbqkAJE87aKHUhXMp",
+                "redeemScript": "5221...52ae"
+            }),
+        )
+        .await;
+
+        let info = client.create_multisig(2, &keys).await.unwrap();
+        assert_eq!(info.address, "2N4Q5FhU2497BryFfUgbqkAJE87aKHUhXMp");
+        assert_eq!(info.redeem_script, "5221...52ae");
+    }
+
+    #[tokio::test]
+    async fn test_create_multisig_invalid_required() {
+        // No request is made, so the client doesn't need a server
+        let client = RpcClient::new("http://localhost:1", None, None).unwrap();
+        let keys = vec!["02aa".to_string(), "03bb".to_string()];
+        for required in [0, 3] {
+            assert!(matches!(
+                client.create_multisig(required, &keys).await,
+                Err(Error::Config(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_tx_fee() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            // The fee rate is sent as an exact decimal amount per kilobyte
+            .and(body_string_contains(r#""params":[0.00000500]"#))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": true,
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        assert!(client
+            .set_tx_fee(FeeRate::from_sats_per_kb(500))
+            .await
+            .unwrap());
+    }
+
+    /// An abridged `help` response.
+    const HELP: &str = "== Blockchain ==\n\
+        getbestblockhash\n\
+        getblock \"blockhash\" ( verbosity )\n\
+        \n\
+        == Control ==\n\
+        getinfo\n\
+        stop\n";
+
+    #[test]
+    fn test_parse_help_methods() {
+        assert_eq!(
+            parse_help_methods(HELP),
+            vec!["getbestblockhash", "getblock", "getinfo", "stop"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_supports_method_fetches_help_once() {
+        // The mock expects exactly one call
+        let (_server, client) =
+            mock_node(json!({"method": "help", "params": []}), json!(HELP)).await;
+
+        assert!(client.supports_method("getblock").await.unwrap());
+        assert!(!client.supports_method("generate").await.unwrap());
+        // Clones share the cache
+        assert!(client.clone().supports_method("stop").await.unwrap());
+        assert_eq!(client.list_methods().await.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_get_new_address() {
+        let (_server, client) = mock_node(
+            json!({"method": "getnewaddress", "params": ["payouts"]}),
+            json!("mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL"),
+        )
+        .await;
+
+        assert_eq!(
+            client.get_new_address(Some("payouts")).await.unwrap(),
+            "mysQxNsz8fxBEGszNcqLwSAbWrj42NnjtL"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_new_address_without_wallet() {
+        let (_server, client) = mock_node_error(
+            json!({"method": "getnewaddress"}),
+            -32601,
+            "Method not found",
+        )
+        .await;
+
+        assert!(matches!(
+            client.get_new_address(None).await,
+            Err(Error::WalletUnavailable)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_exact() {
+        // 21000000.00000001 is not representable as an f64
+        let (_server, client) = mock_node_raw(
+            json!({"method": "getbalance", "params": []}),
+            "21000000.00000001",
+        )
+        .await;
+
+        assert_eq!(
+            client.get_balance(None, false).await.unwrap(),
+            2_100_000_000_000_001
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_with_options() {
+        let (_server, client) = mock_node_raw(
+            json!({"method": "getbalance", "params": ["*", 6, true]}),
+            "0.30000000",
+        )
+        .await;
+
+        assert_eq!(client.get_balance(Some(6), true).await.unwrap(), 30_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_get_unconfirmed_balance() {
+        let (_server, client) =
+            mock_node_raw(json!({"method": "getunconfirmedbalance"}), "1.10000001").await;
+
+        assert_eq!(client.get_unconfirmed_balance().await.unwrap(), 110_000_001);
+    }
+
+    #[tokio::test]
+    async fn test_list_unspent() {
+        let (_server, client) = mock_node_raw(
+            json!({"method": "listunspent", "params": [0, 9_999_999, ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"], false]}),
+            r#"[{
+                "txid": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+                "vout": 0,
+                "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                "scriptPubKey": "76a914243f1394f44554f4ce3fd68649c19adc483ce92488ac",
+                "amount": 0.00000001,
+                "confirmations": 0,
+                "spendable": true,
+                "solvable": true,
+                "safe": false
+            }]"#,
+        )
+        .await;
+
+        let addresses = ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn".to_string()];
+        let unspent = client
+            .list_unspent(Some(0), None, Some(&addresses), false)
+            .await
+            .unwrap();
+        assert_eq!(unspent.len(), 1);
+        assert_eq!(unspent[0].txid.to_string(), GENESIS_COINBASE_TXID);
+        assert_eq!(unspent[0].amount, 1);
+        assert_eq!(unspent[0].confirmations, 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_unspent_defaults() {
+        let (_server, client) =
+            mock_node_raw(json!({"method": "listunspent", "params": []}), "[]").await;
+
+        let unspent = client.list_unspent(None, None, None, true).await.unwrap();
+        assert!(unspent.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_to_address() {
+        let server = MockServer::start().await;
+        // The amount must be sent as exact decimal text, which an f64 can't hold
+        Mock::given(method("POST"))
+            .and(body_string_contains(
+                r#""params":["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",21000000.00000001]"#,
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": GENESIS_COINBASE_TXID,
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        let txid = client
+            .send_to_address(
+                "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                2_100_000_000_000_001,
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(txid.to_string(), GENESIS_COINBASE_TXID);
+    }
+
+    #[tokio::test]
+    async fn test_send_to_address_with_options() {
+        let (_server, client) = mock_node(
+            json!({"method": "sendtoaddress", "params": ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", 0.0005, "rent", "", true]}),
+            json!(GENESIS_COINBASE_TXID),
+        )
+        .await;
+
+        let txid = client
+            .send_to_address(
+                "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                50_000,
+                Some("rent"),
+                true,
+            )
+            .await
+            .unwrap();
+        assert_eq!(txid.to_string(), GENESIS_COINBASE_TXID);
+    }
+
+    #[tokio::test]
+    async fn test_send_to_address_insufficient_funds() {
+        let (_server, client) =
+            mock_node_error(json!({"method": "sendtoaddress"}), -6, "Insufficient funds").await;
+
+        let result = client
+            .send_to_address("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", 50_000, None, false)
+            .await;
+        assert!(matches!(result, Err(Error::Rpc { code: -6, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_send_many() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains(
+                r#""params":["",{"mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn":21000000.00000001},1,"",[]]"#,
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": GENESIS_COINBASE_TXID,
+                "error": null,
+                "id": "bitcoinsv-rpc"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = RpcClient::new(&server.uri(), None, None).unwrap();
+        let outputs = HashMap::from([(
+            "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn".to_string(),
+            2_100_000_000_000_001,
+        )]);
+        let txid = client.send_many(&outputs, None, None, None).await.unwrap();
+        assert_eq!(txid.to_string(), GENESIS_COINBASE_TXID);
+    }
+
+    #[tokio::test]
+    async fn test_send_many_subtract_fee_from_output() {
+        // An address both paid and listed in subtract_fee_from has the fee taken from its output
+        let (_server, client) = mock_node(
+            json!({"method": "sendmany", "params": [
+                "",
+                {"mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn": 0.0005, "mzChange": 0.0001},
+                6,
+                "payroll",
+                ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"]
+            ]}),
+            json!(GENESIS_COINBASE_TXID),
+        )
+        .await;
+
+        let outputs = HashMap::from([
+            ("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn".to_string(), 50_000),
+            ("mzChange".to_string(), 10_000),
+        ]);
+        let subtract = ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn".to_string()];
+        let txid = client
+            .send_many(&outputs, Some(6), Some("payroll"), Some(&subtract))
+            .await
+            .unwrap();
+        assert_eq!(txid.to_string(), GENESIS_COINBASE_TXID);
+    }
+
+    #[tokio::test]
+    async fn test_send_many_subtract_fee_from_unpaid_address() {
+        let client = RpcClient::new("http://127.0.0.1:1", None, None).unwrap();
+        let outputs = HashMap::from([("mzPaid".to_string(), 50_000)]);
+        let subtract = ["mzOther".to_string()];
+
+        let result = client
+            .send_many(&outputs, None, None, Some(&subtract))
+            .await;
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_transactions() {
+        let (_server, client) = mock_node_raw(
+            json!({"method": "listtransactions", "params": ["*", 100, 200, true]}),
+            r#"[{
+                "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                "category": "receive",
+                "amount": 21000000.00000001,
+                "confirmations": 0,
+                "txid": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+                "time": 1700000000
+            }]"#,
+        )
+        .await;
+
+        let entries = client
+            .list_transactions(Some(100), Some(200), true)
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].category, WalletTxCategory::Receive);
+        assert_eq!(entries[0].amount, 2_100_000_000_000_001);
+        assert_eq!(entries[0].txid.unwrap().to_string(), GENESIS_COINBASE_TXID);
+    }
+
+    #[tokio::test]
+    async fn test_get_wallet_transaction() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node(
+            json!({"method": "gettransaction", "params": [GENESIS_COINBASE_TXID]}),
+            json!({
+                "amount": 0,
+                "fee": -0.0005,
+                "confirmations": 6,
+                "blockhash": GENESIS_HASH,
+                "blockindex": 0,
+                "blocktime": 1231006505,
+                "txid": GENESIS_COINBASE_TXID,
+                "walletconflicts": [],
+                "time": 1231006505,
+                "timereceived": 1231006505,
+                "details": [{
+                    "account": "",
+                    "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                    "category": "send",
+                    "amount": -50,
+                    "vout": 0,
+                    "fee": -0.0005,
+                    "abandoned": false
+                }],
+                "hex": GENESIS_COINBASE_HEX
+            }),
+        )
+        .await;
+
+        let wallet_tx = client.get_wallet_transaction(&txid, false).await.unwrap();
+        assert_eq!(wallet_tx.fee, Some(-50_000));
+        assert_eq!(wallet_tx.block_hash.unwrap().to_string(), GENESIS_HASH);
+        assert_eq!(wallet_tx.details[0].category, WalletTxCategory::Send);
+        assert_eq!(wallet_tx.details[0].amount, -5_000_000_000);
+        assert_eq!(wallet_tx.tx().unwrap().hash(), txid);
+    }
+
+    #[tokio::test]
+    async fn test_get_wallet_transaction_not_in_wallet() {
+        let txid = TxHash::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let (_server, client) = mock_node_error(
+            json!({"method": "gettransaction", "params": [GENESIS_COINBASE_TXID, true]}),
+            -5,
+            "Invalid or non-wallet transaction id",
+        )
+        .await;
+
+        let result = client.get_wallet_transaction(&txid, true).await;
+        assert!(matches!(result, Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn test_optional_params() {
+        assert_eq!(optional_params(vec![None, None]), Vec::<Value>::new());
+        assert_eq!(optional_params(vec![Some(json!(1)), None]), vec![json!(1)]);
+        assert_eq!(
+            optional_params(vec![None, Some(json!(10))]),
+            vec![Value::Null, json!(10)]
+        );
+        assert_eq!(
+            optional_params(vec![Some(json!(4)), Some(json!(10))]),
+            vec![json!(4), json!(10)]
+        );
     }
 
     // Integration tests will be in tests/integration_tests.rs
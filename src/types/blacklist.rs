@@ -0,0 +1,291 @@
+//! Frozen transaction output types, for the SV blacklist RPCs.
+//!
+//! These RPCs take and return outputs as `funds` arrays of `{"txOut": {"txId", "vout"}, ...}`
+//! objects, unlike the rest of the RPC interface.
+
+use super::de;
+use bitcoinsv::bitcoin::TxHash;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// A transaction output to freeze or unfreeze.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrozenTxo {
+    /// Id of the transaction containing the output
+    pub txid: TxHash,
+    /// Index of the output
+    pub vout: u32,
+    /// Height intervals in which the consensus freeze is enforced (consensus blacklist only)
+    pub enforce_at_height: Option<Vec<HeightInterval>>,
+    /// Whether the policy freeze ends when the consensus freeze does (consensus blacklist only)
+    pub policy_expires_with_consensus: Option<bool>,
+}
+
+impl FrozenTxo {
+    /// Creates an entry for the given output, without consensus enforcement details.
+    pub fn new(txid: TxHash, vout: u32) -> Self {
+        Self {
+            txid,
+            vout,
+            enforce_at_height: None,
+            policy_expires_with_consensus: None,
+        }
+    }
+}
+
+impl Serialize for FrozenTxo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("txOut", &TxOutRef::new(self))?;
+        if let Some(intervals) = &self.enforce_at_height {
+            map.serialize_entry("enforceAtHeight", intervals)?;
+        }
+        if let Some(expires) = self.policy_expires_with_consensus {
+            map.serialize_entry("policyExpiresWithConsensus", &expires)?;
+        }
+        map.end()
+    }
+}
+
+/// A [`FrozenTxo`] as sent to the policy blacklist RPCs, which only take the output itself.
+pub(crate) struct PolicyFund<'a>(pub(crate) &'a FrozenTxo);
+
+impl Serialize for PolicyFund<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("txOut", &TxOutRef::new(self.0))?;
+        map.end()
+    }
+}
+
+/// The `funds` parameter object of the blacklist RPCs.
+#[derive(Serialize)]
+pub(crate) struct Funds<'a, F> {
+    pub(crate) funds: &'a [F],
+}
+
+/// The `txOut` object of a fund.
+#[derive(Serialize)]
+struct TxOutRef {
+    #[serde(rename = "txId")]
+    txid: String,
+    vout: u32,
+}
+
+impl TxOutRef {
+    fn new(txo: &FrozenTxo) -> Self {
+        Self {
+            txid: txo.txid.to_string(),
+            vout: txo.vout,
+        }
+    }
+}
+
+/// A range of block heights in which a consensus freeze is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeightInterval {
+    /// First height at which the freeze is enforced
+    pub start: u64,
+    /// Height at which the freeze stops being enforced, or `None` if it never does
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<u64>,
+}
+
+/// Result of a blacklist update.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+pub struct BlacklistResult {
+    /// Outputs the node did not process, with the reason
+    #[serde(rename = "notProcessed", default)]
+    pub not_processed: Vec<NotProcessed>,
+}
+
+impl BlacklistResult {
+    /// Returns `true` if the node processed every output.
+    pub fn all_processed(&self) -> bool {
+        self.not_processed.is_empty()
+    }
+}
+
+/// An output that a blacklist update did not process.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "RawNotProcessed")]
+pub struct NotProcessed {
+    /// Id of the transaction containing the output
+    pub txid: TxHash,
+    /// Index of the output
+    pub vout: u32,
+    /// Why the output was not processed
+    pub reason: String,
+}
+
+/// A frozen output, as returned by `queryBlacklist`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "RawFund")]
+pub struct BlacklistEntry {
+    /// The output and its enforcement details
+    pub txo: FrozenTxo,
+    /// The blacklists the output is on
+    pub blacklists: Vec<BlacklistKind>,
+}
+
+/// A blacklist an output can be on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlacklistKind {
+    /// The output is not relayed or mined by this node
+    Policy,
+    /// Blocks spending the output are invalid
+    Consensus,
+}
+
+/// The `txOut` object of a fund, as returned by the node.
+#[derive(Deserialize)]
+struct RawTxOut {
+    #[serde(rename = "txId", deserialize_with = "de::hash")]
+    txid: TxHash,
+    vout: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawNotProcessed {
+    tx_out: RawTxOut,
+    #[serde(default)]
+    reason: String,
+}
+
+impl From<RawNotProcessed> for NotProcessed {
+    fn from(raw: RawNotProcessed) -> Self {
+        Self {
+            txid: raw.tx_out.txid,
+            vout: raw.tx_out.vout,
+            reason: raw.reason,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFund {
+    tx_out: RawTxOut,
+    enforce_at_height: Option<Vec<HeightInterval>>,
+    policy_expires_with_consensus: Option<bool>,
+    #[serde(default)]
+    blacklist: Vec<BlacklistKind>,
+}
+
+impl From<RawFund> for BlacklistEntry {
+    fn from(raw: RawFund) -> Self {
+        Self {
+            txo: FrozenTxo {
+                txid: raw.tx_out.txid,
+                vout: raw.tx_out.vout,
+                enforce_at_height: raw.enforce_at_height,
+                policy_expires_with_consensus: raw.policy_expires_with_consensus,
+            },
+            blacklists: raw.blacklist,
+        }
+    }
+}
+
+/// The result of `queryBlacklist`.
+#[derive(Deserialize)]
+pub(crate) struct BlacklistQuery {
+    #[serde(default)]
+    pub(crate) funds: Vec<BlacklistEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex::FromHex;
+
+    const TXID: &str = "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87";
+
+    #[test]
+    fn test_serialize_funds() {
+        let txid = TxHash::from_hex(TXID).unwrap();
+        let consensus = FrozenTxo {
+            enforce_at_height: Some(vec![HeightInterval {
+                start: 100,
+                stop: Some(200),
+            }]),
+            policy_expires_with_consensus: Some(false),
+            ..FrozenTxo::new(txid, 1)
+        };
+        assert_eq!(
+            serde_json::to_string(&Funds {
+                funds: std::slice::from_ref(&consensus)
+            })
+            .unwrap(),
+            format!(
+                r#"{{"funds":[{{"txOut":{{"txId":"{}","vout":1}},"enforceAtHeight":[{{"start":100,"stop":200}}],"policyExpiresWithConsensus":false}}]}}"#,
+                TXID
+            )
+        );
+
+        // The policy calls only take the output
+        assert_eq!(
+            serde_json::to_string(&Funds {
+                funds: &[PolicyFund(&consensus)]
+            })
+            .unwrap(),
+            format!(
+                r#"{{"funds":[{{"txOut":{{"txId":"{}","vout":1}}}}]}}"#,
+                TXID
+            )
+        );
+    }
+
+    #[test]
+    fn test_deserialize_blacklist_result() {
+        let result: BlacklistResult = serde_json::from_str(&format!(
+            r#"{{"notProcessed": [{{"txOut": {{"txId": "{}", "vout": 3}}, "reason": "already in the consensus blacklist"}}]}}"#,
+            TXID
+        ))
+        .unwrap();
+        assert!(!result.all_processed());
+        assert_eq!(result.not_processed[0].vout, 3);
+        assert_eq!(
+            result.not_processed[0].reason,
+            "already in the consensus blacklist"
+        );
+
+        let result: BlacklistResult = serde_json::from_str(r#"{"notProcessed": []}"#).unwrap();
+        assert!(result.all_processed());
+    }
+
+    #[test]
+    fn test_deserialize_blacklist_query() {
+        let query: BlacklistQuery = serde_json::from_str(&format!(
+            r#"{{"funds": [
+                {{"txOut": {{"txId": "{0}", "vout": 0}}, "blacklist": ["policy"]}},
+                {{
+                    "txOut": {{"txId": "{0}", "vout": 1}},
+                    "enforceAtHeight": [{{"start": 100}}],
+                    "policyExpiresWithConsensus": true,
+                    "blacklist": ["policy", "consensus"]
+                }}
+            ]}}"#,
+            TXID
+        ))
+        .unwrap();
+        assert_eq!(query.funds.len(), 2);
+        assert_eq!(query.funds[0].blacklists, vec![BlacklistKind::Policy]);
+        assert_eq!(query.funds[0].txo.enforce_at_height, None);
+        let entry = &query.funds[1];
+        assert_eq!(entry.txo.vout, 1);
+        assert_eq!(
+            entry.txo.enforce_at_height,
+            Some(vec![HeightInterval {
+                start: 100,
+                stop: None
+            }])
+        );
+        assert_eq!(entry.txo.policy_expires_with_consensus, Some(true));
+        assert_eq!(
+            entry.blacklists,
+            vec![BlacklistKind::Policy, BlacklistKind::Consensus]
+        );
+    }
+}
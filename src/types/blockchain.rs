@@ -0,0 +1,893 @@
+//! Blockchain state types.
+
+use super::de;
+use super::DecodedTransaction;
+use bitcoinsv::bitcoin::{Block, BlockHash, BlockHeader, Encodable, TxHash};
+use hex::FromHex;
+use serde::Deserialize;
+
+/// State of the node's view of the blockchain, as returned by `getblockchaininfo`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BlockchainInfo {
+    /// Network name (`main`, `test`, `stn` or `regtest`)
+    pub chain: String,
+    /// Number of validated blocks in the best chain
+    pub blocks: u64,
+    /// Number of headers the node has validated
+    pub headers: u64,
+    /// Hash of the tip of the best chain
+    #[serde(rename = "bestblockhash", deserialize_with = "de::hash")]
+    pub best_block_hash: BlockHash,
+    /// Current proof-of-work difficulty
+    pub difficulty: f64,
+    /// Median time of the last 11 blocks, in unix seconds
+    #[serde(rename = "mediantime")]
+    pub median_time: u64,
+    /// Estimated verification progress, between 0 and 1
+    #[serde(rename = "verificationprogress")]
+    pub verification_progress: f64,
+    /// Whether the node has pruned block data
+    pub pruned: bool,
+    /// Height of the lowest block still stored, if the node is pruned
+    #[serde(rename = "pruneheight")]
+    pub prune_height: Option<u64>,
+    /// Total chain work of the best chain, as a hex string
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+}
+
+/// The network a node is running on.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum Network {
+    /// Mainnet
+    Main,
+    /// Testnet
+    Test,
+    /// The scaling test network
+    Stn,
+    /// A local regression test network
+    Regtest,
+    /// A network this library does not know about
+    Other(String),
+}
+
+impl From<String> for Network {
+    fn from(chain: String) -> Self {
+        match chain.as_str() {
+            "main" => Network::Main,
+            "test" => Network::Test,
+            "stn" => Network::Stn,
+            "regtest" => Network::Regtest,
+            _ => Network::Other(chain),
+        }
+    }
+}
+
+/// A tip of a chain known to the node, as returned by `getchaintips`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChainTip {
+    /// Height of the chain tip
+    pub height: u64,
+    /// Hash of the tip block
+    #[serde(deserialize_with = "de::hash")]
+    pub hash: BlockHash,
+    /// Length of the branch connecting the tip to the main chain (zero for the active tip)
+    #[serde(rename = "branchlen")]
+    pub branch_len: u64,
+    /// Validation status of the chain
+    pub status: ChainTipStatus,
+}
+
+/// Validation status of a chain tip.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum ChainTipStatus {
+    /// The tip of the best chain
+    Active,
+    /// A fully validated branch that is not part of the best chain
+    ValidFork,
+    /// All blocks are available for the branch but were never fully validated
+    ValidHeaders,
+    /// Not all blocks for the branch are available, but the headers are valid
+    HeadersOnly,
+    /// The branch contains at least one invalid block
+    Invalid,
+    /// A status this library does not know about
+    Unknown(String),
+}
+
+impl From<String> for ChainTipStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "active" => ChainTipStatus::Active,
+            "valid-fork" => ChainTipStatus::ValidFork,
+            "valid-headers" => ChainTipStatus::ValidHeaders,
+            "headers-only" => ChainTipStatus::HeadersOnly,
+            "invalid" => ChainTipStatus::Invalid,
+            _ => ChainTipStatus::Unknown(status),
+        }
+    }
+}
+
+/// Transaction throughput statistics, as returned by `getchaintxstats`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChainTxStats {
+    /// Timestamp of the final block in the window, in unix seconds
+    pub time: u64,
+    /// Total number of transactions in the chain up to the final block in the window
+    #[serde(rename = "txcount")]
+    pub tx_count: u64,
+    /// Hash of the final block in the window (not reported by older nodes)
+    #[serde(default, deserialize_with = "de::option_hash")]
+    pub window_final_block_hash: Option<BlockHash>,
+    /// Number of blocks in the window
+    pub window_block_count: u64,
+    /// Number of transactions in the window (only present if the window is not empty)
+    pub window_tx_count: Option<u64>,
+    /// Elapsed time of the window in seconds (only present if the window is not empty)
+    pub window_interval: Option<u64>,
+    /// Average transactions per second in the window (only present if the interval is positive)
+    #[serde(rename = "txrate")]
+    pub tx_rate: Option<f64>,
+}
+
+/// A block header with its chain context, as returned by the verbose form of `getblockheader`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "RawBlockHeaderInfo")]
+pub struct BlockHeaderInfo {
+    /// Hash of the block
+    pub hash: BlockHash,
+    /// The parsed 80-byte block header
+    pub header: BlockHeader,
+    /// Height of the block
+    pub height: u64,
+    /// Number of confirmations, or `None` if the block is not on the main chain
+    pub confirmations: Option<u64>,
+    /// Whether the block is part of the node's best chain
+    pub in_main_chain: bool,
+    /// Median time of the block, in unix seconds
+    pub median_time: u64,
+    /// Proof-of-work difficulty of the block
+    pub difficulty: f64,
+    /// Total chain work up to and including this block, as a hex string
+    pub chain_work: String,
+    /// Hash of the previous block (`None` for the genesis block)
+    pub previous_block_hash: Option<BlockHash>,
+    /// Hash of the next block on the main chain (`None` for the tip and for stale blocks)
+    pub next_block_hash: Option<BlockHash>,
+}
+
+/// The verbose `getblockheader` response as sent by the node.
+#[derive(Deserialize)]
+struct RawBlockHeaderInfo {
+    #[serde(deserialize_with = "de::hash")]
+    hash: BlockHash,
+    confirmations: i64,
+    height: u64,
+    version: i32,
+    #[serde(rename = "merkleroot")]
+    merkle_root: String,
+    time: u32,
+    #[serde(rename = "mediantime")]
+    median_time: u64,
+    nonce: u32,
+    bits: String,
+    difficulty: f64,
+    #[serde(rename = "chainwork")]
+    chain_work: String,
+    #[serde(rename = "previousblockhash")]
+    previous_block_hash: Option<String>,
+    #[serde(
+        rename = "nextblockhash",
+        default,
+        deserialize_with = "de::option_hash"
+    )]
+    next_block_hash: Option<BlockHash>,
+}
+
+impl TryFrom<RawBlockHeaderInfo> for BlockHeaderInfo {
+    type Error = String;
+
+    fn try_from(raw: RawBlockHeaderInfo) -> Result<Self, Self::Error> {
+        // The verbose response doesn't include the serialized header, so rebuild it from its
+        // fields. Hashes are given in display order and serialized in reverse.
+        let mut bytes = Vec::with_capacity(80);
+        bytes.extend_from_slice(&raw.version.to_le_bytes());
+        match &raw.previous_block_hash {
+            Some(prev) => bytes.extend(decode_hash_bytes(prev)?),
+            None => bytes.extend([0u8; 32]),
+        }
+        bytes.extend(decode_hash_bytes(&raw.merkle_root)?);
+        bytes.extend_from_slice(&raw.time.to_le_bytes());
+        let bits = u32::from_str_radix(&raw.bits, 16)
+            .map_err(|e| format!("invalid bits {}: {}", raw.bits, e))?;
+        bytes.extend_from_slice(&bits.to_le_bytes());
+        bytes.extend_from_slice(&raw.nonce.to_le_bytes());
+
+        let header = BlockHeader::from_binary(&mut &bytes[..])
+            .map_err(|e| format!("failed to parse block header: {}", e))?;
+        if header.hash() != raw.hash {
+            return Err(format!(
+                "block header fields do not hash to block {}",
+                raw.hash
+            ));
+        }
+
+        let previous_block_hash = raw
+            .previous_block_hash
+            .as_deref()
+            .map(BlockHash::from_hex)
+            .transpose()
+            .map_err(|e| format!("invalid previous block hash: {}", e))?;
+
+        Ok(BlockHeaderInfo {
+            hash: raw.hash,
+            header,
+            height: raw.height,
+            // The node reports -1 confirmations for blocks that are not on the main chain
+            confirmations: u64::try_from(raw.confirmations).ok(),
+            in_main_chain: raw.confirmations >= 0,
+            median_time: raw.median_time,
+            difficulty: raw.difficulty,
+            chain_work: raw.chain_work,
+            previous_block_hash,
+            next_block_hash: raw.next_block_hash,
+        })
+    }
+}
+
+/// Decodes a display-order hex hash into its serialized (reversed) byte order.
+fn decode_hash_bytes(hash: &str) -> Result<[u8; 32], String> {
+    let mut bytes =
+        <[u8; 32]>::from_hex(hash).map_err(|e| format!("invalid hash {}: {}", hash, e))?;
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Block metadata and transaction ids, as returned by `getblock` with verbosity 1.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BlockInfo {
+    /// Hash of the block
+    #[serde(deserialize_with = "de::hash")]
+    pub hash: BlockHash,
+    /// Number of confirmations, or `None` if the block is not on the main chain
+    #[serde(deserialize_with = "de::confirmations")]
+    pub confirmations: Option<u64>,
+    /// Size of the block in bytes
+    pub size: u64,
+    /// Height of the block
+    pub height: u64,
+    /// Block version
+    pub version: i32,
+    /// Merkle root of the block's transactions, as a hex string
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// Number of transactions in the block (not reported by older nodes)
+    pub num_tx: Option<u64>,
+    /// Ids of the transactions in the block, in block order
+    #[serde(deserialize_with = "de::hashes")]
+    pub tx: Vec<TxHash>,
+    /// Block timestamp, in unix seconds
+    pub time: u64,
+    /// Median time of the block, in unix seconds
+    #[serde(rename = "mediantime")]
+    pub median_time: u64,
+    /// Block nonce
+    pub nonce: u32,
+    /// Compact difficulty target, as a hex string
+    pub bits: String,
+    /// Proof-of-work difficulty of the block
+    pub difficulty: f64,
+    /// Total chain work up to and including this block, as a hex string
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// Hash of the previous block (`None` for the genesis block)
+    #[serde(
+        rename = "previousblockhash",
+        default,
+        deserialize_with = "de::option_hash"
+    )]
+    pub previous_block_hash: Option<BlockHash>,
+    /// Hash of the next block on the main chain (`None` for the tip and for stale blocks)
+    #[serde(
+        rename = "nextblockhash",
+        default,
+        deserialize_with = "de::option_hash"
+    )]
+    pub next_block_hash: Option<BlockHash>,
+}
+
+impl BlockInfo {
+    /// Returns whether the block is part of the node's best chain.
+    pub fn in_main_chain(&self) -> bool {
+        self.confirmations.is_some()
+    }
+}
+
+/// A block with its transactions decoded by the node, as returned by `getblock` with verbosity 2.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DecodedBlock {
+    /// Hash of the block
+    #[serde(deserialize_with = "de::hash")]
+    pub hash: BlockHash,
+    /// Number of confirmations, or `None` if the block is not on the main chain
+    #[serde(deserialize_with = "de::confirmations")]
+    pub confirmations: Option<u64>,
+    /// Size of the block in bytes
+    pub size: u64,
+    /// Height of the block
+    pub height: u64,
+    /// Block version
+    pub version: i32,
+    /// Merkle root of the block's transactions, as a hex string
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The decoded transactions, in block order
+    pub tx: Vec<DecodedTransaction>,
+    /// Block timestamp, in unix seconds
+    pub time: u64,
+    /// Median time of the block, in unix seconds
+    #[serde(rename = "mediantime")]
+    pub median_time: u64,
+    /// Block nonce
+    pub nonce: u32,
+    /// Compact difficulty target, as a hex string
+    pub bits: String,
+    /// Proof-of-work difficulty of the block
+    pub difficulty: f64,
+    /// Total chain work up to and including this block, as a hex string
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// Hash of the previous block (`None` for the genesis block)
+    #[serde(
+        rename = "previousblockhash",
+        default,
+        deserialize_with = "de::option_hash"
+    )]
+    pub previous_block_hash: Option<BlockHash>,
+    /// Hash of the next block on the main chain (`None` for the tip and for stale blocks)
+    #[serde(
+        rename = "nextblockhash",
+        default,
+        deserialize_with = "de::option_hash"
+    )]
+    pub next_block_hash: Option<BlockHash>,
+}
+
+impl DecodedBlock {
+    /// Returns whether the block is part of the node's best chain.
+    pub fn in_main_chain(&self) -> bool {
+        self.confirmations.is_some()
+    }
+}
+
+/// A block in one of the forms the node can return it in, depending on the requested verbosity.
+pub enum BlockData {
+    /// The complete parsed block (verbosity 0)
+    Raw(Block),
+    /// Block metadata with transaction ids (verbosity 1)
+    Info(BlockInfo),
+    /// Block metadata with decoded transactions (verbosity 2)
+    Decoded(DecodedBlock),
+}
+
+/// The chain tip reported by the `waitfor*` long-poll RPCs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct TipInfo {
+    /// Hash of the tip block
+    #[serde(deserialize_with = "de::hash")]
+    pub hash: BlockHash,
+    /// Height of the tip block
+    pub height: u64,
+}
+
+/// The block to compute statistics for with `getblockstats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockStatsTarget {
+    /// Identify the block by its hash
+    Hash(BlockHash),
+    /// Identify the block by its height in the best chain
+    Height(u64),
+}
+
+/// Per-block statistics, as returned by `getblockstats`.
+///
+/// All fields are optional because the node only returns the statistics that were requested
+/// when a filter is supplied. Fee amounts are in satoshis and fee rates in satoshis per byte.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct BlockStats {
+    /// Hash of the block
+    #[serde(rename = "blockhash", default, deserialize_with = "de::option_hash")]
+    pub block_hash: Option<BlockHash>,
+    /// Height of the block
+    pub height: Option<u64>,
+    /// Block timestamp, in unix seconds
+    pub time: Option<u64>,
+    /// Median time of the block, in unix seconds
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<u64>,
+    /// Number of transactions, including the coinbase
+    pub txs: Option<u64>,
+    /// Number of inputs, excluding the coinbase
+    pub ins: Option<u64>,
+    /// Number of outputs
+    pub outs: Option<u64>,
+    /// Total size of all non-coinbase transactions, in bytes
+    pub total_size: Option<u64>,
+    /// Total amount in all outputs, excluding the coinbase
+    pub total_out: Option<u64>,
+    /// Total fees paid
+    #[serde(rename = "totalfee")]
+    pub total_fee: Option<u64>,
+    /// Block subsidy
+    pub subsidy: Option<u64>,
+    /// Average fee per transaction
+    #[serde(rename = "avgfee")]
+    pub avg_fee: Option<u64>,
+    /// Minimum fee of a transaction
+    #[serde(rename = "minfee")]
+    pub min_fee: Option<u64>,
+    /// Maximum fee of a transaction
+    #[serde(rename = "maxfee")]
+    pub max_fee: Option<u64>,
+    /// Median fee of a transaction
+    #[serde(rename = "medianfee")]
+    pub median_fee: Option<u64>,
+    /// Average fee rate
+    #[serde(rename = "avgfeerate")]
+    pub avg_fee_rate: Option<u64>,
+    /// Minimum fee rate of a transaction
+    #[serde(rename = "minfeerate")]
+    pub min_fee_rate: Option<u64>,
+    /// Maximum fee rate of a transaction
+    #[serde(rename = "maxfeerate")]
+    pub max_fee_rate: Option<u64>,
+    /// Median fee rate of a transaction
+    #[serde(rename = "medianfeerate")]
+    pub median_fee_rate: Option<u64>,
+    /// Fee rates at the 10th, 25th, 50th, 75th and 90th percentiles
+    #[serde(rename = "feerate_percentiles")]
+    pub fee_rate_percentiles: Option<Vec<u64>>,
+    /// Average transaction size, in bytes
+    #[serde(rename = "avgtxsize")]
+    pub avg_tx_size: Option<u64>,
+    /// Minimum transaction size, in bytes
+    #[serde(rename = "mintxsize")]
+    pub min_tx_size: Option<u64>,
+    /// Maximum transaction size, in bytes
+    #[serde(rename = "maxtxsize")]
+    pub max_tx_size: Option<u64>,
+    /// Median transaction size, in bytes
+    #[serde(rename = "mediantxsize")]
+    pub median_tx_size: Option<u64>,
+    /// Change in the number of unspent outputs
+    pub utxo_increase: Option<i64>,
+    /// Change in the size of the UTXO set
+    pub utxo_size_inc: Option<i64>,
+}
+
+/// The node's safe mode state, as returned by the SV `getsafemodeinfo` RPC.
+///
+/// The node enters safe mode when it sees a large fork or reorg that may need operator attention.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SafeModeInfo {
+    /// Whether safe mode is active
+    #[serde(rename = "safemodeenabled")]
+    pub safe_mode_enabled: bool,
+    /// The tip of the active chain
+    #[serde(rename = "activetip")]
+    pub active_tip: SafeModeBlock,
+    /// The most recent reorg, if the node reports one
+    pub reorg: Option<SafeModeReorg>,
+    /// Forks that caused safe mode to be considered
+    #[serde(default)]
+    pub forks: Vec<SafeModeFork>,
+}
+
+/// A block referenced by [`SafeModeInfo`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SafeModeBlock {
+    /// Hash of the block
+    #[serde(deserialize_with = "de::hash")]
+    pub hash: BlockHash,
+    /// Height of the block
+    pub height: u64,
+    /// Validation status of the block, e.g. `active` or `valid-fork`
+    pub status: Option<String>,
+}
+
+/// A reorg reported by [`SafeModeInfo`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SafeModeReorg {
+    /// Whether a reorg happened
+    pub happened: bool,
+    /// Number of blocks disconnected from the active chain
+    #[serde(rename = "numberofdisconnectedblocks", default)]
+    pub disconnected_block_count: u64,
+    /// The blocks disconnected from the active chain
+    #[serde(rename = "disconnectedblocks", default)]
+    pub disconnected_blocks: Vec<SafeModeBlock>,
+}
+
+/// A fork reported by [`SafeModeInfo`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SafeModeFork {
+    /// The first block of the fork
+    #[serde(rename = "forkfirstblock")]
+    pub fork_first_block: SafeModeBlock,
+    /// The tips of the fork
+    #[serde(default)]
+    pub tips: Vec<SafeModeBlock>,
+    /// The last block the fork has in common with the active chain
+    #[serde(rename = "lastcommonblock")]
+    pub last_common_block: SafeModeBlock,
+    /// The first block of the active chain after the common block
+    #[serde(rename = "activechainfirstblock")]
+    pub active_chain_first_block: Option<SafeModeBlock>,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_safe_mode_info() {
+        let info: SafeModeInfo = serde_json::from_str(
+            r#"{
+                "safemodeenabled": true,
+                "activetip": {
+                    "hash": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+                    "height": 100000,
+                    "blocktime": "2010-12-29T11:57:43Z",
+                    "firstblocktime": "2010-12-29T11:57:43Z",
+                    "status": "active"
+                },
+                "reorg": {"happened": false, "numberofdisconnectedblocks": 0, "disconnectedblocks": []},
+                "forks": [
+                    {
+                        "forkfirstblock": {
+                            "hash": "00000000000080b66c911bd5ba14a74260057311eaeb1982802f7010f1a9f090",
+                            "height": 99999,
+                            "status": "valid-headers"
+                        },
+                        "tips": [
+                            {
+                                "hash": "000000000002d01c1fccc21636b607dfd930d31d01c3a62104612a1719011250",
+                                "height": 100006,
+                                "status": "valid-headers"
+                            }
+                        ],
+                        "lastcommonblock": {
+                            "hash": "000000000000d0dfd4c9d588d325dce4f32c1b31b7c0064cba7025a9b9adcc3a",
+                            "height": 99998,
+                            "status": "active"
+                        },
+                        "activechainfirstblock": {
+                            "hash": "000000000002e2a86a2de0a6ea1b0ef7c4d2e1e2b9e8b1b42d2f3b9c3a1bf2d1",
+                            "height": 99999,
+                            "status": "active"
+                        }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(info.safe_mode_enabled);
+        assert_eq!(info.active_tip.height, 100000);
+        assert!(!info.reorg.unwrap().happened);
+        assert_eq!(info.forks.len(), 1);
+        assert_eq!(info.forks[0].tips[0].height, 100006);
+        assert_eq!(info.forks[0].last_common_block.height, 99998);
+    }
+
+    /// Response captured from a mainnet node, including fields the struct doesn't model.
+    const GETBLOCKCHAININFO: &str = r#"{
+        "chain": "main",
+        "blocks": 861234,
+        "headers": 861234,
+        "bestblockhash": "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c",
+        "difficulty": 84345673823.37148,
+        "mediantime": 1724412345,
+        "verificationprogress": 0.9999987654321,
+        "chainwork": "000000000000000000000000000000000000000001611f4c0a8e3b6f5d2e1c0b",
+        "pruned": false,
+        "softforks": [
+            {"id": "bip34", "version": 2, "reject": {"status": true}},
+            {"id": "bip66", "version": 3, "reject": {"status": true}},
+            {"id": "bip65", "version": 4, "reject": {"status": true}}
+        ],
+        "bip9_softforks": {
+            "csv": {"status": "active", "startTime": 1462060800, "timeout": 1493596800, "since": 419328}
+        }
+    }"#;
+
+    #[test]
+    fn test_deserialize_blockchain_info() {
+        let info: BlockchainInfo = serde_json::from_str(GETBLOCKCHAININFO).unwrap();
+        assert_eq!(info.chain, "main");
+        assert_eq!(info.blocks, 861234);
+        assert_eq!(info.headers, 861234);
+        assert_eq!(
+            info.best_block_hash.to_string(),
+            "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c"
+        );
+        assert_eq!(info.difficulty, 84345673823.37148);
+        assert_eq!(info.median_time, 1724412345);
+        assert!(!info.pruned);
+        assert_eq!(info.prune_height, None);
+        assert_eq!(
+            info.chain_work,
+            "000000000000000000000000000000000000000001611f4c0a8e3b6f5d2e1c0b"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_blockchain_info_invalid_hash() {
+        let json = GETBLOCKCHAININFO.replace(
+            "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c",
+            "not-a-hash",
+        );
+        assert!(serde_json::from_str::<BlockchainInfo>(&json).is_err());
+    }
+
+    /// Verbose header of mainnet block 100000.
+    pub(crate) const BLOCK_100000_HEADER: &str = r#"{
+        "hash": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+        "confirmations": 761235,
+        "height": 100000,
+        "version": 1,
+        "versionHex": "00000001",
+        "merkleroot": "f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766",
+        "time": 1293623863,
+        "mediantime": 1293622620,
+        "nonce": 274148111,
+        "bits": "1b04864c",
+        "difficulty": 14484.1623612254,
+        "chainwork": "0000000000000000000000000000000000000000000000000644cb7f5234089e",
+        "previousblockhash": "000000000002d01c1fccc21636b607dfd930d31d01c3a62104612a1719011250",
+        "nextblockhash": "00000000000080b66c911bd5ba14a74260057311eaeb1982802f7010f1a9f090"
+    }"#;
+
+    #[test]
+    fn test_deserialize_block_header_info() {
+        let info: BlockHeaderInfo = serde_json::from_str(BLOCK_100000_HEADER).unwrap();
+        assert_eq!(
+            info.hash.to_string(),
+            "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506"
+        );
+        assert_eq!(info.header.hash(), info.hash);
+        assert_eq!(info.header.nonce(), 274148111);
+        assert_eq!(info.header.timestamp(), 1293623863);
+        assert_eq!(info.height, 100000);
+        assert_eq!(info.confirmations, Some(761235));
+        assert!(info.in_main_chain);
+        assert_eq!(
+            info.previous_block_hash.unwrap().to_string(),
+            "000000000002d01c1fccc21636b607dfd930d31d01c3a62104612a1719011250"
+        );
+        assert_eq!(
+            info.next_block_hash.unwrap().to_string(),
+            "00000000000080b66c911bd5ba14a74260057311eaeb1982802f7010f1a9f090"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_stale_block_header_info() {
+        // A block that was reorganised out: -1 confirmations and no next block
+        let json = BLOCK_100000_HEADER
+            .replace("761235", "-1")
+            .replace(
+                r#""nextblockhash": "00000000000080b66c911bd5ba14a74260057311eaeb1982802f7010f1a9f090""#,
+                r#""ignored": true"#,
+            );
+
+        let info: BlockHeaderInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(info.confirmations, None);
+        assert!(!info.in_main_chain);
+        assert!(info.next_block_hash.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_block_header_info_hash_mismatch() {
+        let json = BLOCK_100000_HEADER.replace("274148111", "274148112");
+        assert!(serde_json::from_str::<BlockHeaderInfo>(&json).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_block_info() {
+        let json = r#"{
+            "tx": [
+                "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+                "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+                "6359f0868171b1d194cbee1af2f16ea598ae8fad666d9b012c8ed2b79a236ec4",
+                "e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d"
+            ],
+            "hash": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+            "confirmations": 761235,
+            "size": 957,
+            "height": 100000,
+            "version": 1,
+            "versionHex": "00000001",
+            "merkleroot": "f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766",
+            "num_tx": 4,
+            "time": 1293623863,
+            "mediantime": 1293622620,
+            "nonce": 274148111,
+            "bits": "1b04864c",
+            "difficulty": 14484.1623612254,
+            "chainwork": "0000000000000000000000000000000000000000000000000644cb7f5234089e",
+            "previousblockhash": "000000000002d01c1fccc21636b607dfd930d31d01c3a62104612a1719011250",
+            "nextblockhash": "00000000000080b66c911bd5ba14a74260057311eaeb1982802f7010f1a9f090"
+        }"#;
+
+        let info: BlockInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.height, 100000);
+        assert_eq!(info.size, 957);
+        assert!(info.in_main_chain());
+        assert_eq!(info.num_tx, Some(4));
+        assert_eq!(info.tx.len(), 4);
+        assert_eq!(
+            info.tx[0].to_string(),
+            "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87"
+        );
+        assert_eq!(
+            info.tx[3].to_string(),
+            "e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_decoded_block() {
+        use crate::types::transaction::tests::{COINBASE_TX, OP_RETURN_TX};
+
+        let json = format!(
+            r#"{{
+                "hash": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+                "confirmations": 1,
+                "size": 957,
+                "height": 100000,
+                "version": 1,
+                "merkleroot": "f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766",
+                "tx": [{}, {}],
+                "time": 1293623863,
+                "mediantime": 1293622620,
+                "nonce": 274148111,
+                "bits": "1b04864c",
+                "difficulty": 14484.1623612254,
+                "chainwork": "0000000000000000000000000000000000000000000000000644cb7f5234089e",
+                "previousblockhash": "000000000002d01c1fccc21636b607dfd930d31d01c3a62104612a1719011250"
+            }}"#,
+            COINBASE_TX, OP_RETURN_TX
+        );
+
+        let block: DecodedBlock = serde_json::from_str(&json).unwrap();
+        assert_eq!(block.height, 100000);
+        assert_eq!(block.tx.len(), 2);
+        assert!(block.tx[0].vin[0].is_coinbase());
+        assert_eq!(block.tx[0].vout[0].value, 5_000_000_000);
+        assert_eq!(block.tx[1].vout[0].script_pub_key.script_type, "nulldata");
+        assert!(block.next_block_hash.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_block_stats() {
+        let json = r#"{
+            "avgfee": 24,
+            "avgfeerate": 0,
+            "avgtxsize": 1053,
+            "blockhash": "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c",
+            "height": 861234,
+            "ins": 48211,
+            "maxfee": 52310,
+            "maxfeerate": 1,
+            "maxtxsize": 998412,
+            "medianfee": 10,
+            "mediantime": 1724412345,
+            "mediantxsize": 227,
+            "minfee": 1,
+            "minfeerate": 0,
+            "mintxsize": 150,
+            "outs": 96532,
+            "subsidy": 312500000,
+            "time": 1724413000,
+            "total_out": 1234567890123,
+            "total_size": 45123456,
+            "totalfee": 1043210,
+            "txs": 42856,
+            "utxo_increase": -120,
+            "utxo_size_inc": -8412
+        }"#;
+
+        let stats: BlockStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.height, Some(861234));
+        assert_eq!(stats.txs, Some(42856));
+        assert_eq!(stats.total_fee, Some(1043210));
+        assert_eq!(stats.utxo_increase, Some(-120));
+        assert!(stats.block_hash.is_some());
+        assert_eq!(stats.fee_rate_percentiles, None);
+    }
+
+    #[test]
+    fn test_deserialize_filtered_block_stats() {
+        let json = r#"{"height": 861234, "totalfee": 1043210}"#;
+
+        let stats: BlockStats = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            stats,
+            BlockStats {
+                height: Some(861234),
+                total_fee: Some(1043210),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_tip_info() {
+        let json = r#"{
+            "hash": "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c",
+            "height": 861234
+        }"#;
+
+        let tip: TipInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(tip.height, 861234);
+        assert_eq!(
+            tip.hash.to_string(),
+            "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_chain_tips() {
+        let json = r#"[
+            {
+                "height": 861234,
+                "hash": "00000000000000000b3d1c1b0e4e5d2ac5d4a4f8a0b7f1d47e5c3e2d1f0a9b8c",
+                "branchlen": 0,
+                "status": "active"
+            },
+            {
+                "height": 861100,
+                "hash": "000000000000000003a5b9d84c1e2f7a6b0c3d4e5f60718293a4b5c6d7e8f901",
+                "branchlen": 1,
+                "status": "valid-fork"
+            },
+            {
+                "height": 556767,
+                "hash": "000000000000000001d956714215d96ffc00e0afda4cd0a96c96f8d802b1662b",
+                "branchlen": 2,
+                "status": "invalid"
+            },
+            {
+                "height": 600000,
+                "hash": "00000000000000000a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778",
+                "branchlen": 5,
+                "status": "headers-only"
+            },
+            {
+                "height": 600001,
+                "hash": "00000000000000000a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566779",
+                "branchlen": 1,
+                "status": "parked"
+            }
+        ]"#;
+
+        let tips: Vec<ChainTip> = serde_json::from_str(json).unwrap();
+        assert_eq!(tips.len(), 5);
+        assert_eq!(tips[0].status, ChainTipStatus::Active);
+        assert_eq!(tips[0].branch_len, 0);
+        assert_eq!(tips[1].status, ChainTipStatus::ValidFork);
+        assert_eq!(tips[2].status, ChainTipStatus::Invalid);
+        assert_eq!(tips[2].height, 556767);
+        assert_eq!(
+            tips[2].hash.to_string(),
+            "000000000000000001d956714215d96ffc00e0afda4cd0a96c96f8d802b1662b"
+        );
+        assert_eq!(tips[3].status, ChainTipStatus::HeadersOnly);
+        assert_eq!(
+            tips[4].status,
+            ChainTipStatus::Unknown("parked".to_string())
+        );
+    }
+}
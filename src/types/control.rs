@@ -0,0 +1,140 @@
+//! Node control types.
+
+use super::{de, Network};
+use serde::Deserialize;
+
+/// Summary of the node's state, as returned by the legacy `getinfo` RPC.
+///
+/// The shape of `getinfo` has changed between node versions, so every field is optional and
+/// is `None` when the node doesn't report it.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct NodeInfo {
+    /// Node version, e.g. `101001600` for 1.0.16
+    pub version: Option<u64>,
+    /// P2P protocol version
+    #[serde(rename = "protocolversion")]
+    pub protocol_version: Option<u64>,
+    /// Height of the tip of the best chain
+    pub blocks: Option<u64>,
+    /// Offset of the node's clock from its peers' median, in seconds
+    #[serde(rename = "timeoffset")]
+    pub time_offset: Option<i64>,
+    /// Number of peer connections
+    pub connections: Option<u64>,
+    /// Proxy used by the node, or an empty string if none
+    pub proxy: Option<String>,
+    /// Current proof-of-work difficulty
+    pub difficulty: Option<f64>,
+    /// Whether the node is running on testnet
+    pub testnet: Option<bool>,
+    /// Whether the node is running on the scaling test network
+    pub stn: Option<bool>,
+    /// The network the node is running on, reported by some node versions instead of `testnet`
+    pub chain: Option<Network>,
+    /// Minimum relay fee rate, in satoshis per kilobyte
+    #[serde(rename = "relayfee", deserialize_with = "de::option_amount")]
+    pub relay_fee: Option<u64>,
+    /// Current warnings
+    pub errors: Option<String>,
+    /// Maximum block size the node accepts, in bytes
+    #[serde(rename = "maxblocksize")]
+    pub max_block_size: Option<u64>,
+    /// Maximum size of blocks the node mines, in bytes
+    #[serde(rename = "maxminedblocksize")]
+    pub max_mined_block_size: Option<u64>,
+}
+
+/// Memory usage of the node, as returned by `getmemoryinfo`.
+///
+/// Builds with different allocators report different extra fields, which are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MemoryInfo {
+    /// Usage of the node's locked (non-swappable) memory pool
+    pub locked: LockedMemoryInfo,
+}
+
+/// Usage of the node's locked memory pool, as part of [`MemoryInfo`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LockedMemoryInfo {
+    /// Bytes in use
+    pub used: u64,
+    /// Bytes available in the current arenas
+    pub free: u64,
+    /// Total bytes managed
+    pub total: u64,
+    /// Bytes that were successfully locked; less than `total` if locking failed
+    pub locked: u64,
+    /// Number of allocated chunks
+    pub chunks_used: u64,
+    /// Number of unused chunks
+    pub chunks_free: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_node_info() {
+        let info: NodeInfo = serde_json::from_str(
+            r#"{
+                "version": 101001600,
+                "protocolversion": 70016,
+                "walletversion": 160300,
+                "balance": 0.00000000,
+                "blocks": 820000,
+                "timeoffset": -1,
+                "connections": 8,
+                "proxy": "",
+                "difficulty": 84345673823.37148,
+                "testnet": false,
+                "stn": false,
+                "keypoololdest": 1699990000,
+                "keypoolsize": 1000,
+                "paytxfee": 0.00000000,
+                "relayfee": 0.00000250,
+                "errors": "",
+                "maxblocksize": 4000000000,
+                "maxminedblocksize": 4000000000
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(info.version, Some(101001600));
+        assert_eq!(info.time_offset, Some(-1));
+        assert_eq!(info.relay_fee, Some(250));
+        assert_eq!(info.testnet, Some(false));
+        assert_eq!(info.chain, None);
+        assert_eq!(info.max_mined_block_size, Some(4_000_000_000));
+    }
+
+    #[test]
+    fn test_deserialize_node_info_missing_fields() {
+        let info: NodeInfo = serde_json::from_str(r#"{"blocks": 5, "chain": "regtest"}"#).unwrap();
+        assert_eq!(info.blocks, Some(5));
+        assert_eq!(info.chain, Some(Network::Regtest));
+        assert_eq!(info.relay_fee, None);
+        assert_eq!(info.version, None);
+    }
+
+    #[test]
+    fn test_deserialize_memory_info() {
+        let info: MemoryInfo = serde_json::from_str(
+            r#"{
+                "locked": {
+                    "used": 65760,
+                    "free": 196384,
+                    "total": 262144,
+                    "locked": 262144,
+                    "chunks_used": 2055,
+                    "chunks_free": 3
+                },
+                "jemalloc": {"allocated": 1024}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(info.locked.used, 65760);
+        assert_eq!(info.locked.total, 262144);
+        assert_eq!(info.locked.chunks_free, 3);
+    }
+}
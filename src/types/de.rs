@@ -0,0 +1,378 @@
+//! Serde helpers shared by the response types.
+
+use crate::amount::parse_btc_amount;
+use hex::FromHex;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::value::RawValue;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Visitor that parses a hash from its display-order hex string without an intermediate `String`.
+struct HashVisitor<H>(PhantomData<H>);
+
+impl<H> Visitor<'_> for HashVisitor<H>
+where
+    H: FromHex,
+    H::Error: fmt::Display,
+{
+    type Value = H;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a hex-encoded hash")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<H, E> {
+        H::from_hex(v).map_err(|e| E::custom(format!("invalid hash {}: {}", v, e)))
+    }
+}
+
+/// Deserializes a hash (e.g. `BlockHash`) from the hex string returned by the node.
+pub(crate) fn hash<'de, D, H>(deserializer: D) -> Result<H, D::Error>
+where
+    D: Deserializer<'de>,
+    H: FromHex,
+    H::Error: fmt::Display,
+{
+    deserializer.deserialize_str(HashVisitor(PhantomData))
+}
+
+/// A hash that deserializes from hex, for use inside containers.
+pub(crate) struct HexHash<H>(pub(crate) H);
+
+impl<'de, H> Deserialize<'de> for HexHash<H>
+where
+    H: FromHex,
+    H::Error: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        hash(deserializer).map(HexHash)
+    }
+}
+
+/// Deserializes an optional hash, where the field may be missing or `null`.
+pub(crate) fn option_hash<'de, D, H>(deserializer: D) -> Result<Option<H>, D::Error>
+where
+    D: Deserializer<'de>,
+    H: FromHex,
+    H::Error: fmt::Display,
+{
+    Option::<HexHash<H>>::deserialize(deserializer).map(|h| h.map(|HexHash(h)| h))
+}
+
+/// Visitor that collects an array of hex hashes directly into a `Vec`.
+struct HashesVisitor<H>(PhantomData<H>);
+
+impl<'de, H> Visitor<'de> for HashesVisitor<H>
+where
+    H: FromHex,
+    H::Error: fmt::Display,
+{
+    type Value = Vec<H>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of hex-encoded hashes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<H>, A::Error> {
+        let mut hashes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(HexHash(hash)) = seq.next_element()? {
+            hashes.push(hash);
+        }
+        Ok(hashes)
+    }
+}
+
+/// Deserializes an array of hashes.
+///
+/// Each hash is parsed straight from the input without allocating a `String`, so very large
+/// arrays (such as the txids of a big block) are handled in linear time.
+pub(crate) fn hashes<'de, D, H>(deserializer: D) -> Result<Vec<H>, D::Error>
+where
+    D: Deserializer<'de>,
+    H: FromHex,
+    H::Error: fmt::Display,
+{
+    deserializer.deserialize_seq(HashesVisitor(PhantomData))
+}
+
+/// A map keyed by hashes, deserialized from a JSON object with hex hash keys.
+///
+/// Entries are inserted as they are read, so no intermediate copy of the object is built.
+pub(crate) struct HexHashMap<H, V>(pub(crate) HashMap<H, V>);
+
+/// Visitor that collects an object with hex hash keys directly into a `HashMap`.
+struct HashMapVisitor<H, V>(PhantomData<(H, V)>);
+
+impl<'de, H, V> Visitor<'de> for HashMapVisitor<H, V>
+where
+    H: FromHex + Eq + Hash,
+    H::Error: fmt::Display,
+    V: Deserialize<'de>,
+{
+    type Value = HashMap<H, V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object keyed by hex-encoded hashes")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<HashMap<H, V>, A::Error> {
+        let mut entries = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((HexHash(key), value)) = map.next_entry()? {
+            entries.insert(key, value);
+        }
+        Ok(entries)
+    }
+}
+
+impl<'de, H, V> Deserialize<'de> for HexHashMap<H, V>
+where
+    H: FromHex + Eq + Hash,
+    H::Error: fmt::Display,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_map(HashMapVisitor(PhantomData))
+            .map(HexHashMap)
+    }
+}
+
+/// Deserializes a 32-bit value given as a hex string, such as compact difficulty bits.
+pub(crate) fn hex_u32<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex = <&str>::deserialize(deserializer)?;
+    u32::from_str_radix(hex, 16)
+        .map_err(|e| de::Error::custom(format!("invalid hex value {}: {}", hex, e)))
+}
+
+/// Deserializes a confirmation count, mapping the node's `-1` for blocks or transactions that
+/// are not on the main chain to `None`.
+pub(crate) fn confirmations<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    i64::deserialize(deserializer).map(|c| u64::try_from(c).ok())
+}
+
+/// Deserializes an index, mapping the node's `-1` for "none" to `None`.
+pub(crate) fn position<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    i64::deserialize(deserializer).map(|p| u32::try_from(p).ok())
+}
+
+/// Deserializes a signed coin amount into satoshis.
+///
+/// The amount is read from its literal JSON text rather than as an `f64`, so the conversion is
+/// exact. Amounts given as numeric strings are also accepted.
+pub(crate) fn signed_amount<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = Box::<RawValue>::deserialize(deserializer)?;
+    let text = raw.get();
+    let text = text
+        .strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .unwrap_or(text);
+    parse_btc_amount(text).map_err(de::Error::custom)
+}
+
+/// Deserializes an optional signed coin amount into satoshis.
+pub(crate) fn option_signed_amount<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<SignedAmount>::deserialize(deserializer).map(|a| a.map(|SignedAmount(sats)| sats))
+}
+
+/// A signed coin amount in satoshis, for use as a whole response or inside containers.
+pub(crate) struct SignedAmount(pub(crate) i64);
+
+impl<'de> Deserialize<'de> for SignedAmount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        signed_amount(deserializer).map(SignedAmount)
+    }
+}
+
+/// A non-negative coin amount in satoshis, for use as a whole response or inside containers.
+pub(crate) struct Amount(pub(crate) u64);
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        amount(deserializer).map(Amount)
+    }
+}
+
+/// Deserializes a non-negative coin amount into satoshis.
+pub(crate) fn amount<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let sats = signed_amount(deserializer)?;
+    u64::try_from(sats).map_err(|_| de::Error::custom(format!("negative amount: {}", sats)))
+}
+
+/// Deserializes an optional non-negative coin amount into satoshis.
+pub(crate) fn option_amount<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Amount>::deserialize(deserializer).map(|a| a.map(|Amount(sats)| sats))
+}
+
+/// Visitor that accepts a floating point value given either as a JSON number or a numeric string.
+struct LenientF64Visitor;
+
+impl Visitor<'_> for LenientF64Visitor {
+    type Value = f64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a number or a numeric string")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<f64, E> {
+        Ok(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<f64, E> {
+        Ok(v as f64)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<f64, E> {
+        Ok(v as f64)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<f64, E> {
+        v.trim()
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Deserializes an `f64` that some node versions return as a number and others as a string.
+pub(crate) fn lenient_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(LenientF64Visitor)
+}
+
+/// An `f64` RPC result that may be returned as a number or a numeric string.
+pub(crate) struct LenientF64(pub(crate) f64);
+
+impl<'de> Deserialize<'de> for LenientF64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        lenient_f64(deserializer).map(LenientF64)
+    }
+}
+
+/// Visitor that accepts a non-negative integer given as an integer, an integral float or a
+/// numeric string.
+struct LenientU64Visitor;
+
+impl Visitor<'_> for LenientU64Visitor {
+    type Value = u64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a non-negative integer")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<u64, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<u64, E> {
+        u64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<u64, E> {
+        if v.fract() == 0.0 && v >= 0.0 && v <= u64::MAX as f64 {
+            Ok(v as u64)
+        } else {
+            Err(E::invalid_value(de::Unexpected::Float(v), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<u64, E> {
+        v.trim()
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Deserializes a `u64` that some node versions return as a float or a string.
+pub(crate) fn lenient_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(LenientU64Visitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lenient_f64_from_number() {
+        let value: LenientF64 = serde_json::from_str("84345673823.37148").unwrap();
+        assert_eq!(value.0, 84345673823.37148);
+
+        let value: LenientF64 = serde_json::from_str("1").unwrap();
+        assert_eq!(value.0, 1.0);
+    }
+
+    #[test]
+    fn test_lenient_f64_from_string() {
+        let value: LenientF64 = serde_json::from_str(r#""84345673823.37148""#).unwrap();
+        assert_eq!(value.0, 84345673823.37148);
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Count(#[serde(deserialize_with = "lenient_u64")] u64);
+
+    #[test]
+    fn test_lenient_u64() {
+        for json in ["42", "42.0", r#""42""#] {
+            let Count(count) = serde_json::from_str(json).unwrap();
+            assert_eq!(count, 42);
+        }
+        for json in ["-1", "42.5", r#""many""#] {
+            assert!(serde_json::from_str::<Count>(json).is_err());
+        }
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Amounts {
+        #[serde(deserialize_with = "amount")]
+        amount: u64,
+        #[serde(deserialize_with = "signed_amount")]
+        signed: i64,
+    }
+
+    #[test]
+    fn test_amount_from_number_and_string() {
+        let amounts: Amounts =
+            serde_json::from_str(r#"{"amount": 21000000.00000001, "signed": -0.0001}"#).unwrap();
+        assert_eq!(amounts.amount, 2_100_000_000_000_001);
+        assert_eq!(amounts.signed, -10_000);
+
+        let amounts: Amounts =
+            serde_json::from_str(r#"{"amount": "0.00000546", "signed": "1"}"#).unwrap();
+        assert_eq!(amounts.amount, 546);
+        assert_eq!(amounts.signed, 100_000_000);
+
+        assert!(serde_json::from_str::<Amounts>(r#"{"amount": -0.0001, "signed": 0}"#).is_err());
+    }
+
+    #[test]
+    fn test_lenient_f64_rejects_garbage() {
+        assert!(serde_json::from_str::<LenientF64>(r#""lots""#).is_err());
+        assert!(serde_json::from_str::<LenientF64>("true").is_err());
+    }
+}
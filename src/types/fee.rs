@@ -0,0 +1,66 @@
+//! Fee types.
+
+use super::de;
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// A fee rate in satoshis per kilobyte (1000 bytes), as used by the node's fee settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FeeRate(u64);
+
+impl FeeRate {
+    /// Creates a fee rate from satoshis per kilobyte.
+    pub fn from_sats_per_kb(sats_per_kb: u64) -> Self {
+        FeeRate(sats_per_kb)
+    }
+
+    /// Returns the fee rate in satoshis per kilobyte.
+    pub fn sats_per_kb(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the fee rate in satoshis per byte, which may be fractional.
+    pub fn sats_per_byte(&self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    /// Returns the fee for a transaction of the given size in bytes, rounded up.
+    pub fn fee_for_size(&self, size: u64) -> u64 {
+        self.0.saturating_mul(size).div_ceil(1000)
+    }
+}
+
+impl fmt::Display for FeeRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} sat/kB", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeeRate {
+    /// Deserializes from the node's coin amount per kilobyte.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        de::amount(deserializer).map(FeeRate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_rate() {
+        let rate = FeeRate::from_sats_per_kb(500);
+        assert_eq!(rate.sats_per_kb(), 500);
+        assert_eq!(rate.sats_per_byte(), 0.5);
+        assert_eq!(rate.fee_for_size(226), 113);
+        assert_eq!(rate.fee_for_size(225), 113);
+        assert_eq!(rate.fee_for_size(0), 0);
+        assert_eq!(rate.to_string(), "500 sat/kB");
+    }
+
+    #[test]
+    fn test_deserialize_fee_rate() {
+        let rate: FeeRate = serde_json::from_str("0.00001").unwrap();
+        assert_eq!(rate, FeeRate::from_sats_per_kb(1000));
+    }
+}
@@ -0,0 +1,216 @@
+//! Mempool types.
+
+use super::de;
+use bitcoinsv::bitcoin::TxHash;
+use serde::Deserialize;
+
+/// State of the node's mempool, as returned by `getmempoolinfo`.
+///
+/// Fields beyond the core counters vary between node versions and are `None` when not reported.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MempoolInfo {
+    /// Number of transactions in the mempool
+    pub size: u64,
+    /// Total size of the transactions in the mempool, in bytes
+    pub bytes: u64,
+    /// Memory used by the mempool, in bytes
+    pub usage: u64,
+    /// Maximum memory the mempool may use, in bytes
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate for a transaction to be accepted, in satoshis per kilobyte
+    #[serde(rename = "mempoolminfee", deserialize_with = "de::amount")]
+    pub mempool_min_fee: u64,
+    /// Number of transactions in the block assembly journal
+    #[serde(rename = "journalsize")]
+    pub journal_size: Option<u64>,
+    /// Number of non-final transactions held in the non-final mempool
+    #[serde(rename = "nonfinalsize")]
+    pub non_final_size: Option<u64>,
+    /// Memory used by the non-final mempool, in bytes
+    #[serde(rename = "nonfinalusage")]
+    pub non_final_usage: Option<u64>,
+    /// Disk space used by transactions written out of the mempool, in bytes
+    #[serde(rename = "usagedisk")]
+    pub usage_disk: Option<u64>,
+    /// Memory used by transactions waiting for a child to pay for them, in bytes
+    #[serde(rename = "usagecpfp")]
+    pub usage_cpfp: Option<u64>,
+    /// Maximum disk space the mempool may use, in bytes
+    #[serde(rename = "maxmempoolsizedisk")]
+    pub max_mempool_size_disk: Option<u64>,
+    /// Maximum memory for transactions waiting for a child to pay for them, in bytes
+    #[serde(rename = "maxmempoolsizecpfp")]
+    pub max_mempool_size_cpfp: Option<u64>,
+}
+
+/// A transaction in the mempool, as returned by the verbose forms of `getrawmempool`,
+/// `getmempoolancestors` and `getmempooldescendants`, and by `getmempoolentry`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MempoolEntry {
+    /// Size of the transaction in bytes
+    pub size: u64,
+    /// Fee paid by the transaction, in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub fee: u64,
+    /// Fee used for mining priority, including any `prioritisetransaction` delta, in satoshis
+    #[serde(rename = "modifiedfee", deserialize_with = "de::amount")]
+    pub modified_fee: u64,
+    /// Time the transaction entered the mempool, in unix seconds
+    pub time: u64,
+    /// Block height when the transaction entered the mempool
+    pub height: u64,
+    /// Unconfirmed transactions this transaction spends outputs of
+    #[serde(deserialize_with = "de::hashes")]
+    pub depends: Vec<TxHash>,
+    /// Number of in-mempool descendants, including this transaction
+    #[serde(rename = "descendantcount")]
+    pub descendant_count: Option<u64>,
+    /// Size of in-mempool descendants, including this transaction, in bytes
+    #[serde(rename = "descendantsize")]
+    pub descendant_size: Option<u64>,
+    /// Modified fees of in-mempool descendants, including this transaction, in satoshis
+    #[serde(rename = "descendantfees")]
+    pub descendant_fees: Option<u64>,
+    /// Number of in-mempool ancestors, including this transaction
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: Option<u64>,
+    /// Size of in-mempool ancestors, including this transaction, in bytes
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: Option<u64>,
+    /// Modified fees of in-mempool ancestors, including this transaction, in satoshis
+    #[serde(rename = "ancestorfees")]
+    pub ancestor_fees: Option<u64>,
+}
+
+/// Result of checking the mempool journal against the mempool, as returned by `checkjournal`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct JournalCheckResult {
+    /// Whether the journal is consistent with the mempool
+    pub ok: bool,
+    /// Description of the inconsistencies found (empty if the journal is consistent)
+    pub errors: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::de::HexHashMap;
+    use hex::FromHex;
+
+    /// A verbose `getrawmempool` response with a parent and a child transaction.
+    const VERBOSE_MEMPOOL: &str = r#"{
+        "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4": {
+            "size": 226,
+            "fee": 0.00000113,
+            "modifiedfee": 0.00000113,
+            "time": 1700000000,
+            "height": 820000,
+            "depends": [],
+            "descendantcount": 2,
+            "descendantsize": 417,
+            "descendantfees": 209,
+            "ancestorcount": 1,
+            "ancestorsize": 226,
+            "ancestorfees": 113
+        },
+        "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87": {
+            "size": 191,
+            "fee": 0.00000096,
+            "modifiedfee": 1.00000096,
+            "time": 1700000005,
+            "height": 820000,
+            "depends": ["fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4"]
+        }
+    }"#;
+
+    #[test]
+    fn test_deserialize_verbose_mempool() {
+        let HexHashMap(entries): HexHashMap<TxHash, MempoolEntry> =
+            serde_json::from_str(VERBOSE_MEMPOOL).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let parent =
+            TxHash::from_hex("fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4")
+                .unwrap();
+        let child =
+            TxHash::from_hex("8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87")
+                .unwrap();
+
+        let entry = &entries[&parent];
+        assert_eq!(entry.fee, 113);
+        assert_eq!(entry.descendant_count, Some(2));
+        assert_eq!(entry.ancestor_fees, Some(113));
+
+        // Nodes that don't report the ancestor and descendant statistics
+        let entry = &entries[&child];
+        assert_eq!(entry.modified_fee, 100_000_096);
+        assert_eq!(entry.depends, vec![parent]);
+        assert_eq!(entry.descendant_count, None);
+    }
+
+    #[test]
+    fn test_deserialize_sv_mempool_info() {
+        let info: MempoolInfo = serde_json::from_str(
+            r#"{
+                "size": 5123,
+                "journalsize": 5100,
+                "nonfinalsize": 2,
+                "bytes": 2301564,
+                "usage": 7012480,
+                "usagedisk": 0,
+                "usagecpfp": 1024,
+                "nonfinalusage": 1664,
+                "maxmempool": 10000000000,
+                "maxmempoolsizedisk": 30000000000,
+                "maxmempoolsizecpfp": 1000000000,
+                "mempoolminfee": 0.00000500,
+                "someNewField": true
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(info.size, 5123);
+        assert_eq!(info.journal_size, Some(5100));
+        assert_eq!(info.non_final_size, Some(2));
+        assert_eq!(info.mempool_min_fee, 500);
+        assert_eq!(info.max_mempool_size_cpfp, Some(1_000_000_000));
+    }
+
+    #[test]
+    fn test_deserialize_minimal_mempool_info() {
+        let info: MempoolInfo = serde_json::from_str(
+            r#"{
+                "size": 0,
+                "bytes": 0,
+                "usage": 96,
+                "maxmempool": 300000000,
+                "mempoolminfee": 0.00001000
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(info.mempool_min_fee, 1_000);
+        assert_eq!(info.journal_size, None);
+        assert_eq!(info.usage_disk, None);
+    }
+
+    #[test]
+    fn test_deserialize_healthy_journal() {
+        let result: JournalCheckResult =
+            serde_json::from_str(r#"{"ok": true, "errors": ""}"#).unwrap();
+        assert!(result.ok);
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_corrupt_journal() {
+        let result: JournalCheckResult = serde_json::from_str(
+            r#"{
+                "ok": false,
+                "errors": "Transaction 8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87 in mempool but not in journal\n"
+            }"#,
+        )
+        .unwrap();
+        assert!(!result.ok);
+        assert!(result.errors.contains("not in journal"));
+    }
+}
@@ -0,0 +1,277 @@
+//! Merkle proof types.
+
+use super::BlockHeaderInfo;
+use bitcoinsv::bitcoin::{BlockHash, TxHash};
+use hex::FromHex;
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A proof that a transaction is included in a block, as returned by the SV `getmerkleproof` RPC.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MerkleProof {
+    /// Flags describing the format of `tx_or_id` and `target`
+    pub flags: u8,
+    /// Index of the transaction in the block
+    pub index: u64,
+    /// The transaction id, or the full transaction as hex, depending on `flags`
+    #[serde(rename = "txOrId")]
+    pub tx_or_id: String,
+    /// The block the proof is for
+    pub target: MerkleProofTarget,
+    /// The merkle branch from the transaction up to the merkle root
+    pub nodes: Vec<MerkleNode>,
+}
+
+/// The block a [`MerkleProof`] is for.
+///
+/// Depending on its configuration the node identifies the block by its hash or includes the
+/// block header.
+#[derive(Debug, Clone)]
+pub enum MerkleProofTarget {
+    /// Hash of the block
+    Hash(BlockHash),
+    /// The block header with its chain context
+    Header(Box<BlockHeaderInfo>),
+}
+
+impl<'de> Deserialize<'de> for MerkleProofTarget {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TargetVisitor;
+
+        impl<'de> Visitor<'de> for TargetVisitor {
+            type Value = MerkleProofTarget;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a block hash or a block header object")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                BlockHash::from_hex(value)
+                    .map(MerkleProofTarget::Hash)
+                    .map_err(|e| E::custom(format!("invalid block hash {}: {}", value, e)))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                let header =
+                    BlockHeaderInfo::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(MerkleProofTarget::Header(Box::new(header)))
+            }
+        }
+
+        deserializer.deserialize_any(TargetVisitor)
+    }
+}
+
+/// A node in a merkle branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleNode {
+    /// The hash of the sibling at this level
+    Hash(TxHash),
+    /// The sibling is a duplicate of the hash computed so far (sent by the node as `*`)
+    Duplicate,
+}
+
+impl<'de> Deserialize<'de> for MerkleNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NodeVisitor;
+
+        impl Visitor<'_> for NodeVisitor {
+            type Value = MerkleNode;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a hex-encoded hash or \"*\"")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                if value == "*" {
+                    return Ok(MerkleNode::Duplicate);
+                }
+                TxHash::from_hex(value)
+                    .map(MerkleNode::Hash)
+                    .map_err(|e| E::custom(format!("invalid merkle node {}: {}", value, e)))
+            }
+        }
+
+        deserializer.deserialize_str(NodeVisitor)
+    }
+}
+
+impl Serialize for MerkleNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MerkleNode::Hash(hash) => serializer.collect_str(hash),
+            MerkleNode::Duplicate => serializer.serialize_str("*"),
+        }
+    }
+}
+
+/// A merkle proof in the TSC standard format, as returned by the SV `getmerkleproof2` RPC.
+///
+/// Serializing the proof produces the same TSC JSON the node returned, so it can be passed on to
+/// other services unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TscMerkleProof {
+    /// Index of the transaction in the block
+    pub index: u64,
+    /// The transaction id, or the full transaction as hex
+    pub tx_or_id: String,
+    /// What `target` contains (a block hash if `None`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<TargetType>,
+    /// The block hash, block header or merkle root, as hex
+    pub target: String,
+    /// The merkle branch from the transaction up to the merkle root
+    pub nodes: Vec<MerkleNode>,
+}
+
+/// What the target of a [`TscMerkleProof`] contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TargetType {
+    /// The hash of the block
+    Hash,
+    /// The 80-byte block header
+    Header,
+    /// The merkle root of the block
+    MerkleRoot,
+}
+
+impl TargetType {
+    /// Returns the value of the `targetType` parameter of `getmerkleproof2`.
+    pub(crate) fn as_param(&self) -> &'static str {
+        match self {
+            TargetType::Hash => "hash",
+            TargetType::Header => "header",
+            TargetType::MerkleRoot => "merkleroot",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The coinbase of block 100000 proven with the block hash as target.
+    const PROOF_WITH_HASH: &str = r#"{
+        "flags": 0,
+        "index": 0,
+        "txOrId": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+        "target": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+        "nodes": [
+            "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+            "8e30899078ca1813be036a073bbf80b86cdddde1c96e9e9c99e9e3782df4ae49"
+        ]
+    }"#;
+
+    #[test]
+    fn test_deserialize_merkle_proof_with_hash_target() {
+        let proof: MerkleProof = serde_json::from_str(PROOF_WITH_HASH).unwrap();
+        assert_eq!(proof.flags, 0);
+        assert_eq!(proof.index, 0);
+        match proof.target {
+            MerkleProofTarget::Hash(hash) => assert_eq!(
+                hash.to_string(),
+                "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506"
+            ),
+            other => panic!("expected a hash target, got {:?}", other),
+        }
+        assert_eq!(proof.nodes.len(), 2);
+    }
+
+    #[test]
+    fn test_deserialize_merkle_proof_with_header_target() {
+        let json = PROOF_WITH_HASH.replace(
+            r#""000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506""#,
+            crate::types::blockchain::tests::BLOCK_100000_HEADER,
+        );
+        let proof: MerkleProof = serde_json::from_str(&json).unwrap();
+        match proof.target {
+            MerkleProofTarget::Header(header) => {
+                assert_eq!(header.height, 100000);
+                assert_eq!(
+                    header.hash.to_string(),
+                    "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506"
+                );
+            }
+            other => panic!("expected a header target, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_merkle_node() {
+        let nodes: Vec<MerkleNode> = serde_json::from_str(
+            r#"["*", "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4"]"#,
+        )
+        .unwrap();
+        assert_eq!(nodes[0], MerkleNode::Duplicate);
+        assert!(matches!(nodes[1], MerkleNode::Hash(_)));
+
+        // Nodes serialize back to the node's format
+        assert_eq!(
+            serde_json::to_string(&nodes).unwrap(),
+            r#"["*","fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4"]"#
+        );
+    }
+
+    /// Asserts that a TSC proof deserializes and serializes back to the same JSON.
+    fn assert_round_trip(json: &str) -> TscMerkleProof {
+        let proof: TscMerkleProof = serde_json::from_str(json).unwrap();
+        // The fixtures only have whitespace between tokens, so this is the compact form
+        let compact: String = json.split_whitespace().collect();
+        assert_eq!(serde_json::to_string(&proof).unwrap(), compact);
+        proof
+    }
+
+    #[test]
+    fn test_tsc_proof_with_hash_target() {
+        // The target type is omitted for a block hash target
+        let proof = assert_round_trip(
+            r#"{
+                "index": 1,
+                "txOrId": "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+                "target": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+                "nodes": [
+                    "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+                    "*"
+                ]
+            }"#,
+        );
+        assert_eq!(proof.target_type, None);
+        assert_eq!(proof.nodes[1], MerkleNode::Duplicate);
+    }
+
+    #[test]
+    fn test_tsc_proof_with_header_target() {
+        let proof = assert_round_trip(
+            r#"{
+                "index": 0,
+                "txOrId": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+                "targetType": "header",
+                "target": "0100000050120119172a610421a6c3011dd330d9df07b63616c2cc1f1cd00200000000006657a9252aacd5c0b2940996ecff952228c3067cc38d4885efb5a4ac4247e9f337221b4d4c86041b0f2b5710",
+                "nodes": [
+                    "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+                    "8e30899078ca1813be036a073bbf80b86cdddde1c96e9e9c99e9e3782df4ae49"
+                ]
+            }"#,
+        );
+        assert_eq!(proof.target_type, Some(TargetType::Header));
+        assert_eq!(proof.target.len(), 160);
+    }
+
+    #[test]
+    fn test_tsc_proof_with_merkle_root_target() {
+        let proof = assert_round_trip(
+            r#"{
+                "index": 0,
+                "txOrId": "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff08044c86041b020602ffffffff0100f2052a010000004341041b0e8c2567c12536aa13357b79a073dc4444acb83c4ec7a0e2f99dd7457516c5817242da796924ca4e99947d087fedf9ce467cb9f7c6287078f801df276fdf84ac00000000",
+                "targetType": "merkleRoot",
+                "target": "f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766",
+                "nodes": []
+            }"#,
+        );
+        assert_eq!(proof.target_type, Some(TargetType::MerkleRoot));
+        assert_eq!(TargetType::MerkleRoot.as_param(), "merkleroot");
+    }
+}
@@ -0,0 +1,341 @@
+//! Mining types.
+
+use super::{de, Network};
+use bitcoinsv::bitcoin::{BlockHash, TxHash};
+use serde::{Deserialize, Serialize};
+
+/// Mining-related state of the node, as returned by `getmininginfo`.
+///
+/// Numeric fields are accepted as integers, floats or strings, as node versions differ.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MiningInfo {
+    /// Height of the tip of the best chain
+    #[serde(deserialize_with = "de::lenient_u64")]
+    pub blocks: u64,
+    /// Size of the last block template, in bytes
+    #[serde(rename = "currentblocksize", deserialize_with = "de::lenient_u64")]
+    pub current_block_size: u64,
+    /// Number of transactions in the last block template
+    #[serde(rename = "currentblocktx", deserialize_with = "de::lenient_u64")]
+    pub current_block_tx: u64,
+    /// Current proof-of-work difficulty
+    #[serde(deserialize_with = "de::lenient_f64")]
+    pub difficulty: f64,
+    /// Estimated network hash rate, in hashes per second
+    #[serde(rename = "networkhashps", deserialize_with = "de::lenient_f64")]
+    pub network_hash_ps: f64,
+    /// Number of transactions in the mempool
+    #[serde(rename = "pooledtx", deserialize_with = "de::lenient_u64")]
+    pub pooled_tx: u64,
+    /// The network the node is running on
+    pub chain: Network,
+}
+
+/// A block to mine, as returned by the SV `getminingcandidate` RPC.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MiningCandidate {
+    /// Identifier of the candidate, used when submitting a solution
+    pub id: String,
+    /// Hash of the block the candidate builds on
+    #[serde(rename = "prevhash", deserialize_with = "de::hash")]
+    pub prev_hash: BlockHash,
+    /// The coinbase transaction as hex, if requested
+    pub coinbase: Option<String>,
+    /// Total value available to the coinbase (subsidy plus fees), in satoshis
+    pub coinbase_value: u64,
+    /// Block version
+    pub version: i32,
+    /// Compact difficulty target
+    #[serde(rename = "nBits", deserialize_with = "de::hex_u32")]
+    pub bits: u32,
+    /// Block timestamp, in unix seconds
+    pub time: u32,
+    /// Height of the candidate block
+    pub height: u64,
+    /// Number of transactions in the candidate, including the coinbase
+    #[serde(rename = "num_tx")]
+    pub num_tx: Option<u64>,
+    /// Size of the candidate without the coinbase, in bytes
+    pub size_without_coinbase: Option<u64>,
+    /// Merkle branch for the coinbase transaction, in internal byte order
+    #[serde(deserialize_with = "de::hashes")]
+    pub merkle_proof: Vec<TxHash>,
+}
+
+/// A solution for a [`MiningCandidate`], submitted with the SV `submitminingsolution` RPC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MiningSolution {
+    /// Identifier of the candidate that was solved
+    pub id: String,
+    /// The nonce that solves the block
+    pub nonce: u32,
+    /// The coinbase transaction as hex (the candidate's coinbase if `None`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coinbase: Option<String>,
+    /// Block timestamp, in unix seconds (the candidate's time if `None`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<u32>,
+    /// Block version (the candidate's version if `None`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+}
+
+/// Parameters for the `getblocktemplate` RPC.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BlockTemplateRequest {
+    /// Whether to request a template or submit a block proposal (`template` if `None`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<TemplateMode>,
+    /// Features supported by the client
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<TemplateCapability>,
+    /// Consensus rules supported by the client
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<TemplateRule>,
+}
+
+/// The `mode` of a `getblocktemplate` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateMode {
+    /// Request a new block template
+    Template,
+    /// Submit a block proposal for validation
+    Proposal,
+}
+
+/// A client capability advertised in a `getblocktemplate` request (BIP 22/23).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateCapability {
+    /// Long polling for template updates
+    LongPoll,
+    /// The client can use a full coinbase transaction
+    CoinbaseTxn,
+    /// The client can build its own coinbase from the coinbase value
+    CoinbaseValue,
+    /// Block proposals
+    Proposal,
+    /// Alternative server lists
+    ServerList,
+    /// Work identifiers
+    WorkId,
+}
+
+/// A consensus rule advertised in a `getblocktemplate` request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum TemplateRule {
+    /// Relative lock-time (BIP 68/112/113)
+    #[serde(rename = "csv")]
+    Csv,
+    /// Any other rule, sent as given
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A block template, as returned by `getblocktemplate`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BlockTemplate {
+    /// Block version
+    pub version: i32,
+    /// Hash of the block the template builds on
+    #[serde(rename = "previousblockhash", deserialize_with = "de::hash")]
+    pub previous_block_hash: BlockHash,
+    /// Transactions to include in the block, excluding the coinbase
+    pub transactions: Vec<BlockTemplateTransaction>,
+    /// Total value available to the coinbase (subsidy plus fees), in satoshis
+    #[serde(rename = "coinbasevalue")]
+    pub coinbase_value: u64,
+    /// Proof-of-work target, as a hex string
+    pub target: String,
+    /// Minimum timestamp for the block, in unix seconds
+    #[serde(rename = "mintime")]
+    pub min_time: u64,
+    /// Valid range of nonces, as a hex string
+    #[serde(rename = "noncerange")]
+    pub nonce_range: String,
+    /// Current time according to the node, in unix seconds
+    #[serde(rename = "curtime")]
+    pub cur_time: u64,
+    /// Compact difficulty target
+    #[serde(deserialize_with = "de::hex_u32")]
+    pub bits: u32,
+    /// Height of the block to be mined
+    pub height: u64,
+}
+
+/// A transaction in a [`BlockTemplate`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BlockTemplateTransaction {
+    /// The serialized transaction as hex
+    pub data: String,
+    /// Transaction id
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Indexes (1-based) of earlier template transactions this one depends on
+    #[serde(default)]
+    pub depends: Vec<u32>,
+    /// Fee paid by the transaction, in satoshis
+    pub fee: u64,
+    /// Number of signature operations, if reported by the node
+    pub sigops: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex::FromHex;
+
+    #[test]
+    fn test_serialize_mining_solution() {
+        let solution = MiningSolution {
+            id: "a5c6ef4b".to_string(),
+            nonce: 274148111,
+            coinbase: Some("0100".to_string()),
+            time: None,
+            version: Some(536870912),
+        };
+        assert_eq!(
+            serde_json::to_string(&solution).unwrap(),
+            r#"{"id":"a5c6ef4b","nonce":274148111,"coinbase":"0100","version":536870912}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_block_template_request() {
+        let request = BlockTemplateRequest {
+            mode: None,
+            capabilities: vec![
+                TemplateCapability::CoinbaseTxn,
+                TemplateCapability::LongPoll,
+            ],
+            rules: vec![TemplateRule::Csv, TemplateRule::Other("future".to_string())],
+        };
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"capabilities":["coinbasetxn","longpoll"],"rules":["csv","future"]}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&BlockTemplateRequest::default()).unwrap(),
+            "{}"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_block_template() {
+        let template: BlockTemplate = serde_json::from_str(
+            r#"{
+                "capabilities": ["proposal"],
+                "version": 536870912,
+                "previousblockhash": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+                "transactions": [
+                    {
+                        "data": "0100000001",
+                        "txid": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+                        "hash": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+                        "depends": [],
+                        "fee": 226,
+                        "sigops": 2
+                    }
+                ],
+                "coinbaseaux": {"flags": ""},
+                "coinbasevalue": 625000226,
+                "longpollid": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e5065",
+                "target": "00000000000404cb000000000000000000000000000000000000000000000000",
+                "mintime": 1699998000,
+                "mutable": ["time", "transactions", "prevblock"],
+                "noncerange": "00000000ffffffff",
+                "sizelimit": 4000000000,
+                "curtime": 1700000000,
+                "bits": "1b04864c",
+                "height": 100001
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(template.coinbase_value, 625_000_226);
+        assert_eq!(template.bits, 0x1b04864c);
+        assert_eq!(template.transactions.len(), 1);
+        assert_eq!(template.transactions[0].fee, 226);
+        assert_eq!(template.transactions[0].sigops, Some(2));
+        assert_eq!(
+            template.transactions[0].txid,
+            TxHash::from_hex("8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_mining_candidate() {
+        let candidate: MiningCandidate = serde_json::from_str(
+            r#"{
+                "id": "a5c6ef4b-2b0b-4ff3-8fbd-df7e4a7b1b2c",
+                "prevhash": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+                "coinbaseValue": 625012345,
+                "version": 536870912,
+                "nBits": "1b04864c",
+                "time": 1700000000,
+                "height": 100001,
+                "num_tx": 3,
+                "sizeWithoutCoinbase": 417,
+                "merkleProof": [
+                    "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+                    "8e30899078ca1813be036a073bbf80b86cdddde1c96e9e9c99e9e3782df4ae49"
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(candidate.coinbase, None);
+        assert_eq!(candidate.coinbase_value, 625_012_345);
+        assert_eq!(candidate.bits, 0x1b04864c);
+        assert_eq!(candidate.num_tx, Some(3));
+
+        // Branch hashes are given in display order and stored in internal order
+        let branch =
+            TxHash::from_hex("fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4")
+                .unwrap();
+        assert_eq!(candidate.merkle_proof[0], branch);
+        assert_eq!(candidate.merkle_proof.len(), 2);
+    }
+
+    #[test]
+    fn test_deserialize_mining_info() {
+        let info: MiningInfo = serde_json::from_str(
+            r#"{
+                "blocks": 820000,
+                "currentblocksize": 1048576,
+                "currentblocktx": 2500,
+                "difficulty": 84345673823.37148,
+                "errors": "",
+                "networkhashps": 6.041218853405232e+17,
+                "pooledtx": 5123,
+                "chain": "main"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(info.blocks, 820000);
+        assert_eq!(info.current_block_tx, 2500);
+        assert_eq!(info.chain, Network::Main);
+        assert!(info.network_hash_ps > 6.0e17);
+    }
+
+    #[test]
+    fn test_deserialize_mining_info_mixed_number_forms() {
+        let info: MiningInfo = serde_json::from_str(
+            r#"{
+                "blocks": 150.0,
+                "currentblocksize": "0",
+                "currentblocktx": 0,
+                "difficulty": "4.656542373906925e-10",
+                "networkhashps": 12,
+                "pooledtx": 3.0,
+                "chain": "someday"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(info.blocks, 150);
+        assert_eq!(info.pooled_tx, 3);
+        assert_eq!(info.network_hash_ps, 12.0);
+        assert_eq!(info.chain, Network::Other("someday".to_string()));
+    }
+}
@@ -0,0 +1,52 @@
+//! Typed responses returned by the Bitcoin SV node, and typed parameters sent to it.
+//!
+//! Structs in this module ignore fields they don't know about, so that responses from newer
+//! node versions still deserialize. Coin amounts are converted exactly to satoshis.
+
+mod blacklist;
+mod blockchain;
+mod control;
+pub(crate) mod de;
+mod fee;
+mod mempool;
+mod merkle;
+mod mining;
+mod network;
+pub(crate) mod ser;
+mod transaction;
+mod util;
+mod wallet;
+
+pub use blacklist::{
+    BlacklistEntry, BlacklistKind, BlacklistResult, FrozenTxo, HeightInterval, NotProcessed,
+};
+pub(crate) use blacklist::{BlacklistQuery, Funds, PolicyFund};
+pub use blockchain::{
+    BlockData, BlockHeaderInfo, BlockInfo, BlockStats, BlockStatsTarget, BlockchainInfo, ChainTip,
+    ChainTipStatus, ChainTxStats, DecodedBlock, Network, SafeModeBlock, SafeModeFork, SafeModeInfo,
+    SafeModeReorg, TipInfo,
+};
+pub use control::{LockedMemoryInfo, MemoryInfo, NodeInfo};
+pub use fee::FeeRate;
+pub use mempool::{JournalCheckResult, MempoolEntry, MempoolInfo};
+pub use merkle::{MerkleNode, MerkleProof, MerkleProofTarget, TargetType, TscMerkleProof};
+pub use mining::{
+    BlockTemplate, BlockTemplateRequest, BlockTemplateTransaction, MiningCandidate, MiningInfo,
+    MiningSolution, TemplateCapability, TemplateMode, TemplateRule,
+};
+pub use network::{
+    AddNodeCommand, AddedNodeAddress, AddedNodeInfo, BanCommand, BannedEntry, ConnectionDirection,
+    DisconnectTarget, NetTotals, PeerInfo, UploadTarget,
+};
+pub(crate) use transaction::TxOutsResult;
+pub use transaction::{
+    DecodedInput, DecodedOutput, DecodedTransaction, OutPoint, RawTxSubmission,
+    RejectedTransaction, ScriptCheck, ScriptCheckResult, ScriptCheckStatus, ScriptCheckTxo,
+    ScriptPubKey, ScriptSig, SendRawTransactionsResult, TransactionInfo, TxInput, TxOutInfo,
+    TxOutsEntry, UnconfirmedAncestor, UnconfirmedAncestors, UtxoSetEntry, UtxoSetResult,
+};
+pub use util::{AddressValidation, MultisigInfo};
+pub use wallet::{
+    AddressGroupEntry, FundRawOptions, FundRawResult, ReceivedByAddress, Unspent, WalletInfo,
+    WalletTransaction, WalletTxCategory, WalletTxDetail, WalletTxInfo,
+};
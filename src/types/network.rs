@@ -0,0 +1,370 @@
+//! Network types.
+
+use serde::Deserialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A connected peer, as returned by `getpeerinfo`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PeerInfo {
+    /// Node-local identifier of the peer
+    pub id: u64,
+    /// Address and port of the peer, which may be an IPv6 literal or an onion address
+    pub addr: String,
+    /// Our own address as seen by the peer, if known
+    #[serde(rename = "addrlocal")]
+    pub addr_local: Option<String>,
+    /// Services offered by the peer, as a hex bit field
+    pub services: String,
+    /// Time of the last message sent to the peer, in unix seconds
+    #[serde(rename = "lastsend")]
+    pub last_send: u64,
+    /// Time of the last message received from the peer, in unix seconds
+    #[serde(rename = "lastrecv")]
+    pub last_recv: u64,
+    /// Total bytes sent to the peer
+    #[serde(rename = "bytessent")]
+    pub bytes_sent: u64,
+    /// Total bytes received from the peer
+    #[serde(rename = "bytesrecv")]
+    pub bytes_recv: u64,
+    /// Time the connection was made, in unix seconds
+    #[serde(rename = "conntime")]
+    pub conn_time: u64,
+    /// Round trip time of the last ping, in seconds, or `None` if the peer hasn't answered yet
+    #[serde(rename = "pingtime")]
+    pub ping_time: Option<f64>,
+    /// Lowest ping round trip time seen, in seconds
+    #[serde(rename = "minping")]
+    pub min_ping: Option<f64>,
+    /// Protocol version advertised by the peer
+    pub version: u32,
+    /// User agent advertised by the peer (e.g. `/Bitcoin SV:1.0.16/`)
+    pub subver: String,
+    /// Whether the peer connected to us
+    pub inbound: bool,
+    /// Height of the peer's best chain when the connection was made
+    #[serde(rename = "startingheight")]
+    pub starting_height: i64,
+    /// Misbehaviour score; the peer is banned when it reaches the node's threshold
+    #[serde(rename = "banscore")]
+    pub ban_score: i64,
+    /// Height of the last header the peer and this node have in common, or -1 if unknown
+    pub synced_headers: i64,
+    /// Height of the last block the peer and this node have in common, or -1 if unknown
+    pub synced_blocks: i64,
+    /// Whether the peer is whitelisted
+    pub whitelisted: bool,
+}
+
+/// Network traffic totals, as returned by `getnettotals`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NetTotals {
+    /// Total bytes received
+    #[serde(rename = "totalbytesrecv")]
+    pub total_bytes_recv: u64,
+    /// Total bytes sent
+    #[serde(rename = "totalbytessent")]
+    pub total_bytes_sent: u64,
+    /// Time of the snapshot, in unix milliseconds
+    #[serde(rename = "timemillis")]
+    pub time_millis: u64,
+    /// The outbound upload target, or `None` if the node doesn't report one
+    #[serde(rename = "uploadtarget")]
+    pub upload_target: Option<UploadTarget>,
+}
+
+/// State of the node's outbound upload target (`-maxuploadtarget`), as part of [`NetTotals`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct UploadTarget {
+    /// Length of the measuring cycle, in seconds
+    #[serde(rename = "timeframe")]
+    pub time_frame: u64,
+    /// Target in bytes per cycle, or 0 if there is no limit
+    pub target: u64,
+    /// Whether the target has been reached in the current cycle
+    pub target_reached: bool,
+    /// Whether historical blocks are still served to peers
+    pub serve_historical_blocks: bool,
+    /// Bytes left in the current cycle
+    pub bytes_left_in_cycle: u64,
+    /// Seconds left in the current cycle
+    pub time_left_in_cycle: u64,
+}
+
+/// The action to take with `addnode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddNodeCommand {
+    /// Add the node to the list of peers the node keeps connected
+    Add,
+    /// Remove the node from the list of added peers
+    Remove,
+    /// Try a single connection to the node
+    OneTry,
+}
+
+impl AddNodeCommand {
+    /// Returns the value of the `command` parameter of `addnode`.
+    pub(crate) fn as_param(&self) -> &'static str {
+        match self {
+            AddNodeCommand::Add => "add",
+            AddNodeCommand::Remove => "remove",
+            AddNodeCommand::OneTry => "onetry",
+        }
+    }
+}
+
+/// The peer to disconnect with `disconnectnode`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisconnectTarget {
+    /// The peer's address, as shown in [`PeerInfo::addr`]
+    Address(String),
+    /// The peer's node id, as shown in [`PeerInfo::id`]
+    NodeId(u64),
+}
+
+/// The action to take with `setban`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BanCommand {
+    /// Ban the subnet
+    Add,
+    /// Lift the ban on the subnet
+    Remove,
+}
+
+impl BanCommand {
+    /// Returns the value of the `command` parameter of `setban`.
+    pub(crate) fn as_param(&self) -> &'static str {
+        match self {
+            BanCommand::Add => "add",
+            BanCommand::Remove => "remove",
+        }
+    }
+}
+
+/// A banned address or subnet, as returned by `listbanned`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BannedEntry {
+    /// The banned address or subnet, e.g. `192.0.2.0/24`
+    pub address: String,
+    /// Time the ban ends, in unix seconds
+    pub banned_until: u64,
+    /// Time the ban was created, in unix seconds
+    pub ban_created: u64,
+    /// Why the peer was banned, e.g. `manually added` or `node misbehaving`
+    pub ban_reason: String,
+}
+
+impl BannedEntry {
+    /// Returns the time the ban ends.
+    pub fn banned_until_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.banned_until)
+    }
+
+    /// Returns the time the ban was created.
+    pub fn ban_created_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.ban_created)
+    }
+}
+
+/// A peer added with `addnode`, as returned by `getaddednodeinfo`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AddedNodeInfo {
+    /// The address the peer was added with
+    #[serde(rename = "addednode")]
+    pub added_node: String,
+    /// Whether the node is connected to the peer
+    pub connected: bool,
+    /// Resolved addresses of the peer and their connection state
+    #[serde(default)]
+    pub addresses: Vec<AddedNodeAddress>,
+}
+
+/// A resolved address of an [`AddedNodeInfo`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AddedNodeAddress {
+    /// Address and port
+    pub address: String,
+    /// Direction of the connection to the address, if any
+    pub connected: ConnectionDirection,
+}
+
+/// Direction of a connection to an added node address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionDirection {
+    /// The peer connected to us
+    Inbound,
+    /// We connected to the peer
+    Outbound,
+    /// Not connected
+    False,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `getpeerinfo` response with an outbound IPv4 peer and an inbound IPv6 peer that
+    /// hasn't answered a ping yet.
+    const PEER_INFO: &str = r#"[
+        {
+            "id": 3,
+            "addr": "203.0.113.7:8333",
+            "addrlocal": "198.51.100.2:51234",
+            "services": "0000000000000025",
+            "relaytxes": true,
+            "lastsend": 1700000100,
+            "lastrecv": 1700000101,
+            "bytessent": 123456,
+            "bytesrecv": 9876543,
+            "conntime": 1699990000,
+            "timeoffset": 0,
+            "pingtime": 0.0813,
+            "minping": 0.0794,
+            "version": 70016,
+            "subver": "/Bitcoin SV:1.0.16/",
+            "inbound": false,
+            "startingheight": 820000,
+            "banscore": 0,
+            "synced_headers": 820005,
+            "synced_blocks": 820005,
+            "inflight": [],
+            "whitelisted": false,
+            "bytessent_per_msg": {"ping": 320},
+            "bytesrecv_per_msg": {"pong": 320}
+        },
+        {
+            "id": 7,
+            "addr": "[2001:db8::1]:50412",
+            "services": "0000000000000021",
+            "lastsend": 1700000090,
+            "lastrecv": 0,
+            "bytessent": 250,
+            "bytesrecv": 0,
+            "conntime": 1700000090,
+            "timeoffset": 0,
+            "pingwait": 2.5,
+            "version": 70015,
+            "subver": "/Bitcoin SV:1.0.11/",
+            "inbound": true,
+            "startingheight": -1,
+            "banscore": 0,
+            "synced_headers": -1,
+            "synced_blocks": -1,
+            "inflight": [],
+            "whitelisted": false
+        }
+    ]"#;
+
+    #[test]
+    fn test_deserialize_net_totals() {
+        let totals: NetTotals = serde_json::from_str(
+            r#"{
+                "totalbytesrecv": 61724812044,
+                "totalbytessent": 5021393512,
+                "timemillis": 1700000000123,
+                "uploadtarget": {
+                    "timeframe": 86400,
+                    "target": 0,
+                    "target_reached": false,
+                    "serve_historical_blocks": true,
+                    "bytes_left_in_cycle": 0,
+                    "time_left_in_cycle": 0
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(totals.total_bytes_recv, 61_724_812_044);
+        assert_eq!(totals.time_millis, 1_700_000_000_123);
+        assert!(totals.upload_target.unwrap().serve_historical_blocks);
+
+        let totals: NetTotals =
+            serde_json::from_str(r#"{"totalbytesrecv": 1, "totalbytessent": 2, "timemillis": 3}"#)
+                .unwrap();
+        assert_eq!(totals.upload_target, None);
+    }
+
+    #[test]
+    fn test_deserialize_banned_entries() {
+        let banned: Vec<BannedEntry> = serde_json::from_str(
+            r#"[
+                {
+                    "address": "192.0.2.0/24",
+                    "banned_until": 1700086400,
+                    "ban_created": 1700000000,
+                    "ban_reason": "manually added"
+                },
+                {
+                    "address": "2001:db8::1/128",
+                    "banned_until": 1700090000,
+                    "ban_created": 1700003600,
+                    "ban_reason": "node misbehaving"
+                }
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(banned.len(), 2);
+        assert_eq!(banned[0].address, "192.0.2.0/24");
+        assert_eq!(banned[1].ban_reason, "node misbehaving");
+        assert_eq!(
+            banned[0]
+                .banned_until_time()
+                .duration_since(banned[0].ban_created_time())
+                .unwrap(),
+            Duration::from_secs(86400)
+        );
+
+        let banned: Vec<BannedEntry> = serde_json::from_str("[]").unwrap();
+        assert!(banned.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_added_node_info() {
+        let nodes: Vec<AddedNodeInfo> = serde_json::from_str(
+            r#"[
+                {
+                    "addednode": "203.0.113.7:8333",
+                    "connected": true,
+                    "addresses": [
+                        {"address": "203.0.113.7:8333", "connected": "outbound"}
+                    ]
+                },
+                {
+                    "addednode": "node.example.com",
+                    "connected": false,
+                    "addresses": [
+                        {"address": "198.51.100.9:8333", "connected": "false"}
+                    ]
+                }
+            ]"#,
+        )
+        .unwrap();
+        assert!(nodes[0].connected);
+        assert_eq!(
+            nodes[0].addresses[0].connected,
+            ConnectionDirection::Outbound
+        );
+        assert!(!nodes[1].connected);
+        assert_eq!(nodes[1].addresses[0].connected, ConnectionDirection::False);
+    }
+
+    #[test]
+    fn test_deserialize_peer_info() {
+        let peers: Vec<PeerInfo> = serde_json::from_str(PEER_INFO).unwrap();
+        assert_eq!(peers.len(), 2);
+
+        let outbound = &peers[0];
+        assert!(!outbound.inbound);
+        assert_eq!(outbound.addr, "203.0.113.7:8333");
+        assert_eq!(outbound.ping_time, Some(0.0813));
+        assert_eq!(outbound.bytes_recv, 9876543);
+        assert_eq!(outbound.synced_blocks, 820005);
+
+        let inbound = &peers[1];
+        assert!(inbound.inbound);
+        assert_eq!(inbound.addr, "[2001:db8::1]:50412");
+        assert_eq!(inbound.addr_local, None);
+        assert_eq!(inbound.ping_time, None);
+        assert_eq!(inbound.synced_headers, -1);
+    }
+}
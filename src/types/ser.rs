@@ -0,0 +1,49 @@
+//! Serde helpers for the parameters sent to the node.
+
+use crate::amount::format_btc_amount;
+use serde::ser::{Error, Serialize, Serializer};
+use serde_json::value::RawValue;
+
+/// A satoshi amount that serializes as an exact decimal coin amount.
+///
+/// The amount is written as a JSON number with eight decimal places, without going through
+/// `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Amount(pub(crate) u64);
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RawValue::from_string(format_btc_amount(self.0))
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// Serializes a satoshi amount as an exact decimal coin amount.
+pub(crate) fn amount<S: Serializer>(amount: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    Amount(*amount).serialize(serializer)
+}
+
+/// Serializes an optional satoshi amount as an exact decimal coin amount.
+pub(crate) fn option_amount<S: Serializer>(
+    amount: &Option<u64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    amount.map(Amount).serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_amount() {
+        let json = serde_json::to_string(&Amount(2_099_999_999_999_999)).unwrap();
+        assert_eq!(json, "20999999.99999999");
+        assert_eq!(serde_json::to_string(&Amount(1)).unwrap(), "0.00000001");
+        assert_eq!(
+            serde_json::to_string(&[Amount(0), Amount(150_000_000)]).unwrap(),
+            "[0.00000000,1.50000000]"
+        );
+    }
+}
@@ -0,0 +1,800 @@
+//! Transaction types.
+
+use super::{de, ser};
+use bitcoinsv::bitcoin::{BlockHash, TxHash};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A transaction decoded by the node.
+///
+/// This is the node's JSON representation of a transaction, shared by `getblock` (verbosity 2)
+/// and `decoderawtransaction`. `getrawtransaction` (verbose) returns the same fields plus
+/// confirmation metadata, see [`TransactionInfo`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DecodedTransaction {
+    /// Transaction id
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Transaction hash (the same as the txid on Bitcoin SV)
+    #[serde(deserialize_with = "de::hash")]
+    pub hash: TxHash,
+    /// Transaction version
+    pub version: i32,
+    /// Serialized size in bytes
+    pub size: u64,
+    /// Lock time
+    #[serde(rename = "locktime")]
+    pub lock_time: u32,
+    /// Inputs
+    pub vin: Vec<DecodedInput>,
+    /// Outputs
+    pub vout: Vec<DecodedOutput>,
+    /// The serialized transaction as hex, when the node includes it
+    pub hex: Option<String>,
+}
+
+/// A transaction with its confirmation metadata, as returned by `getrawtransaction` (verbose).
+///
+/// The inputs and outputs use the same types as [`DecodedTransaction`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TransactionInfo {
+    /// Transaction id
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Transaction hash (the same as the txid on Bitcoin SV)
+    #[serde(deserialize_with = "de::hash")]
+    pub hash: TxHash,
+    /// Transaction version
+    pub version: i32,
+    /// Serialized size in bytes
+    pub size: u64,
+    /// Lock time
+    #[serde(rename = "locktime")]
+    pub lock_time: u32,
+    /// Inputs
+    pub vin: Vec<DecodedInput>,
+    /// Outputs
+    pub vout: Vec<DecodedOutput>,
+    /// The serialized transaction as hex
+    pub hex: String,
+    /// Hash of the block containing the transaction (`None` for a mempool transaction)
+    #[serde(rename = "blockhash", default, deserialize_with = "de::option_hash")]
+    pub block_hash: Option<BlockHash>,
+    /// Number of confirmations (`None` for a mempool transaction, zero if the containing block
+    /// is not on the main chain)
+    pub confirmations: Option<u64>,
+    /// Time the transaction was mined, in unix seconds (the same as `block_time`)
+    pub time: Option<u64>,
+    /// Timestamp of the containing block, in unix seconds
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<u64>,
+}
+
+impl TransactionInfo {
+    /// Returns whether the transaction is in a block on the node's best chain.
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmations.is_some_and(|c| c > 0)
+    }
+}
+
+/// A decoded transaction input.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DecodedInput {
+    /// The coinbase data as hex, for the input of a coinbase transaction
+    pub coinbase: Option<String>,
+    /// Id of the transaction containing the spent output (`None` for a coinbase input)
+    #[serde(default, deserialize_with = "de::option_hash")]
+    pub txid: Option<TxHash>,
+    /// Index of the spent output (`None` for a coinbase input)
+    pub vout: Option<u32>,
+    /// The unlocking script (`None` for a coinbase input)
+    #[serde(rename = "scriptSig")]
+    pub script_sig: Option<ScriptSig>,
+    /// Sequence number
+    pub sequence: u32,
+}
+
+impl DecodedInput {
+    /// Returns whether this is the input of a coinbase transaction.
+    pub fn is_coinbase(&self) -> bool {
+        self.coinbase.is_some()
+    }
+}
+
+/// A decoded unlocking script.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ScriptSig {
+    /// The script in assembly form
+    pub asm: String,
+    /// The script as hex
+    pub hex: String,
+}
+
+/// A decoded transaction output.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DecodedOutput {
+    /// Value of the output in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub value: u64,
+    /// Index of the output in the transaction
+    pub n: u32,
+    /// The locking script
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: ScriptPubKey,
+}
+
+/// A decoded locking script.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ScriptPubKey {
+    /// The script in assembly form
+    pub asm: String,
+    /// The script as hex
+    pub hex: String,
+    /// Number of signatures required to spend the output, for standard scripts
+    #[serde(rename = "reqSigs")]
+    pub req_sigs: Option<u32>,
+    /// Script type, e.g. `pubkeyhash`, `nulldata` or `nonstandard`
+    #[serde(rename = "type")]
+    pub script_type: String,
+    /// Addresses the output pays to, for standard scripts
+    #[serde(default)]
+    pub addresses: Vec<String>,
+}
+
+/// A transaction to submit with `sendrawtransactions`, with its per-transaction flags.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct RawTxSubmission {
+    /// The serialized transaction as hex
+    pub hex: String,
+    /// Accept the transaction even if its fee is unusually high
+    #[serde(rename = "allowhighfees", skip_serializing_if = "is_false")]
+    pub allow_high_fees: bool,
+    /// Skip the node's minimum fee check
+    #[serde(rename = "dontcheckfee", skip_serializing_if = "is_false")]
+    pub dont_check_fee: bool,
+    /// Report the transaction's unconfirmed ancestors in the result
+    #[serde(rename = "listunconfirmedancestors", skip_serializing_if = "is_false")]
+    pub list_unconfirmed_ancestors: bool,
+}
+
+impl RawTxSubmission {
+    /// Creates a submission for the given hex-encoded transaction with all flags unset.
+    pub fn new(hex: impl Into<String>) -> Self {
+        Self {
+            hex: hex.into(),
+            ..Self::default()
+        }
+    }
+}
+
+fn is_false(flag: &bool) -> bool {
+    !flag
+}
+
+/// An unspent transaction output, as returned by `gettxout`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TxOutInfo {
+    /// Hash of the tip of the best chain when the output was looked up
+    #[serde(rename = "bestblock", deserialize_with = "de::hash")]
+    pub best_block: BlockHash,
+    /// Number of confirmations of the transaction (zero if it is in the mempool)
+    pub confirmations: u64,
+    /// Value of the output in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub value: u64,
+    /// The locking script
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: ScriptPubKey,
+    /// Whether the output belongs to a coinbase transaction
+    pub coinbase: bool,
+}
+
+/// The status of a set of outputs, as returned by the REST `getutxos` endpoint.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UtxoSetResult {
+    /// Height of the tip of the best chain when the outputs were looked up
+    #[serde(rename = "chainHeight")]
+    pub chain_height: u64,
+    /// Hash of the tip of the best chain when the outputs were looked up
+    #[serde(rename = "chaintipHash", deserialize_with = "de::hash")]
+    pub chain_tip_hash: BlockHash,
+    /// Whether each requested output is unspent, in the order requested
+    #[serde(deserialize_with = "utxo_bitmap")]
+    pub bitmap: Vec<bool>,
+    /// The unspent outputs, in the order requested, skipping those that are spent or unknown
+    pub utxos: Vec<UtxoSetEntry>,
+}
+
+/// An unspent output in a [`UtxoSetResult`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UtxoSetEntry {
+    /// Height of the block containing the output, or `0x7fffffff` for mempool outputs
+    pub height: u64,
+    /// Value of the output in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub value: u64,
+    /// The locking script
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: ScriptPubKey,
+}
+
+/// Deserializes the `getutxos` bitmap, a string with a `0` or `1` for each requested output.
+fn utxo_bitmap<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<bool>, D::Error> {
+    let bitmap = <&str>::deserialize(deserializer)?;
+    bitmap
+        .chars()
+        .map(|c| match c {
+            '0' => Ok(false),
+            '1' => Ok(true),
+            _ => Err(serde::de::Error::custom(format!(
+                "invalid utxo bitmap: {}",
+                bitmap
+            ))),
+        })
+        .collect()
+}
+
+/// An unspent transaction output, as returned by the SV `gettxouts` RPC.
+///
+/// `gettxouts` reports a different set of fields than `gettxout` and only includes the fields
+/// that were requested, so they are all optional.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TxOutsEntry {
+    /// The locking script as hex
+    pub script_pub_key: Option<String>,
+    /// Length of the locking script in bytes
+    pub script_pub_key_len: Option<u64>,
+    /// Value of the output in satoshis
+    pub value: Option<u64>,
+    /// Whether the locking script is standard
+    pub is_standard: Option<bool>,
+    /// Number of confirmations of the transaction (zero if it is in the mempool)
+    pub confirmations: Option<u64>,
+}
+
+/// Result of the SV `gettxouts` RPC.
+#[derive(Debug, Deserialize)]
+pub(crate) struct TxOutsResult {
+    /// One entry per requested outpoint, in request order
+    #[serde(deserialize_with = "txouts")]
+    pub(crate) txouts: Vec<Option<TxOutsEntry>>,
+}
+
+/// Deserializes the `txouts` array of a `gettxouts` result, mapping spent and unknown outputs
+/// (which the node reports with an `error` field) to `None`.
+fn txouts<'de, D>(deserializer: D) -> Result<Vec<Option<TxOutsEntry>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Entry {
+        error: Option<String>,
+        script_pub_key: Option<String>,
+        script_pub_key_len: Option<u64>,
+        #[serde(default, deserialize_with = "de::option_amount")]
+        value: Option<u64>,
+        is_standard: Option<bool>,
+        confirmations: Option<u64>,
+    }
+
+    let entries = Vec::<Entry>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|e| {
+            e.error.is_none().then_some(TxOutsEntry {
+                script_pub_key: e.script_pub_key,
+                script_pub_key_len: e.script_pub_key_len,
+                value: e.value,
+                is_standard: e.is_standard,
+                confirmations: e.confirmations,
+            })
+        })
+        .collect())
+}
+
+/// An output to spend in a transaction built with `createrawtransaction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxInput {
+    /// Id of the transaction containing the output
+    pub txid: TxHash,
+    /// Index of the output
+    pub vout: u32,
+    /// Sequence number of the input (the node uses its default if `None`)
+    pub sequence: Option<u32>,
+}
+
+impl TxInput {
+    /// Creates an input spending the given output, with the default sequence number.
+    pub fn new(txid: TxHash, vout: u32) -> Self {
+        Self {
+            txid,
+            vout,
+            sequence: None,
+        }
+    }
+}
+
+impl Serialize for TxInput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = if self.sequence.is_some() { 3 } else { 2 };
+        let mut state = serializer.serialize_struct("TxInput", len)?;
+        state.serialize_field("txid", &self.txid.to_string())?;
+        state.serialize_field("vout", &self.vout)?;
+        if let Some(sequence) = self.sequence {
+            state.serialize_field("sequence", &sequence)?;
+        }
+        state.end()
+    }
+}
+
+/// Result of a `sendrawtransactions` batch submission.
+///
+/// Transactions that don't appear in any of the lists were accepted.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct SendRawTransactionsResult {
+    /// Transactions the node already knew about
+    #[serde(default, deserialize_with = "de::hashes")]
+    pub known: Vec<TxHash>,
+    /// Transactions that were accepted and then evicted from the mempool
+    #[serde(default, deserialize_with = "de::hashes")]
+    pub evicted: Vec<TxHash>,
+    /// Transactions the node rejected
+    #[serde(default)]
+    pub invalid: Vec<RejectedTransaction>,
+    /// Unconfirmed ancestors of the transactions submitted with `list_unconfirmed_ancestors`
+    #[serde(default)]
+    pub unconfirmed: Vec<UnconfirmedAncestors>,
+}
+
+/// A transaction rejected by `sendrawtransactions`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RejectedTransaction {
+    /// Id of the rejected transaction
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Reject code, when the node provides one
+    pub reject_code: Option<i32>,
+    /// Reason for the rejection, e.g. `bad-txns-inputs-missingorspent`
+    pub reject_reason: String,
+    /// Ids of mempool transactions the rejected transaction conflicts with
+    #[serde(rename = "collidedWith", default, deserialize_with = "collided_txids")]
+    pub collided_with: Vec<TxHash>,
+}
+
+/// Deserializes the ids from the node's list of colliding transactions.
+fn collided_txids<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<TxHash>, D::Error> {
+    #[derive(Deserialize)]
+    struct Collided {
+        #[serde(deserialize_with = "de::hash")]
+        txid: TxHash,
+    }
+
+    let collided = Vec::<Collided>::deserialize(deserializer)?;
+    Ok(collided.into_iter().map(|c| c.txid).collect())
+}
+
+/// The unconfirmed ancestors of a submitted transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UnconfirmedAncestors {
+    /// Id of the submitted transaction
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Its ancestors that are still in the mempool
+    pub ancestors: Vec<UnconfirmedAncestor>,
+}
+
+/// An unconfirmed ancestor of a submitted transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UnconfirmedAncestor {
+    /// Id of the ancestor
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Outputs spent by the ancestor
+    pub vin: Vec<OutPoint>,
+}
+
+/// A reference to a transaction output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct OutPoint {
+    /// Id of the transaction containing the output
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Index of the output
+    pub vout: u32,
+}
+
+/// A transaction input to check with `verifyscript`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScriptCheck {
+    /// The serialized spending transaction as hex
+    pub tx: String,
+    /// Index of the input to check
+    pub n: u32,
+    /// The output being spent; the node looks it up if `None`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txo: Option<ScriptCheckTxo>,
+}
+
+impl ScriptCheck {
+    /// Creates a check for an input, letting the node look up the output it spends.
+    pub fn new(tx: impl Into<String>, n: u32) -> Self {
+        Self {
+            tx: tx.into(),
+            n,
+            txo: None,
+        }
+    }
+}
+
+/// The output spent by a [`ScriptCheck`], given explicitly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScriptCheckTxo {
+    /// The output's locking script (scriptPubKey) as hex
+    #[serde(rename = "lock")]
+    pub script_pub_key: String,
+    /// Value of the output, in satoshis
+    #[serde(serialize_with = "ser::amount")]
+    pub value: u64,
+}
+
+/// The outcome of a [`ScriptCheck`], as returned by `verifyscript`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ScriptCheckResult {
+    /// Whether the script was verified
+    pub result: ScriptCheckStatus,
+    /// Why the check failed or was not completed
+    pub description: Option<String>,
+}
+
+impl ScriptCheckResult {
+    /// Returns `true` if the script executed successfully.
+    pub fn is_ok(&self) -> bool {
+        self.result == ScriptCheckStatus::Ok
+    }
+}
+
+/// Status of a [`ScriptCheckResult`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptCheckStatus {
+    /// The script executed successfully
+    Ok,
+    /// The script failed or the check could not be made
+    Error,
+    /// The check ran out of time
+    Timeout,
+    /// The check was skipped after an earlier check failed
+    Skipped,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use hex::FromHex;
+
+    #[test]
+    fn test_serialize_script_check() {
+        let check = ScriptCheck {
+            tx: "0100".to_string(),
+            n: 1,
+            txo: Some(ScriptCheckTxo {
+                script_pub_key: "76a914".to_string(),
+                value: 1_000,
+            }),
+        };
+        assert_eq!(
+            serde_json::to_string(&check).unwrap(),
+            r#"{"tx":"0100","n":1,"txo":{"lock":"76a914","value":0.00001000}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ScriptCheck::new("0100", 0)).unwrap(),
+            r#"{"tx":"0100","n":0}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_script_check_results() {
+        let results: Vec<ScriptCheckResult> = serde_json::from_str(
+            r#"[
+                {"result": "ok"},
+                {"result": "error", "description": "Script evaluation failed"},
+                {"result": "skipped"}
+            ]"#,
+        )
+        .unwrap();
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].result, ScriptCheckStatus::Error);
+        assert_eq!(
+            results[1].description.as_deref(),
+            Some("Script evaluation failed")
+        );
+        assert_eq!(results[2].result, ScriptCheckStatus::Skipped);
+    }
+
+    /// A coinbase transaction, as decoded by the node.
+    pub(crate) const COINBASE_TX: &str = r#"{
+        "txid": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+        "hash": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+        "version": 1,
+        "size": 135,
+        "locktime": 0,
+        "vin": [
+            {
+                "coinbase": "044c86041b020602",
+                "sequence": 4294967295
+            }
+        ],
+        "vout": [
+            {
+                "value": 50.00000000,
+                "n": 0,
+                "scriptPubKey": {
+                    "asm": "041b0e8c2567c12536aa13357b79a073dc4444acb83c4ec7a0e2f99dd7457516c5817242da796924ca4e99947d087fedf9ce467cb9f7c6287078f801df276fdf84 OP_CHECKSIG",
+                    "hex": "41041b0e8c2567c12536aa13357b79a073dc4444acb83c4ec7a0e2f99dd7457516c5817242da796924ca4e99947d087fedf9ce467cb9f7c6287078f801df276fdf84ac",
+                    "reqSigs": 1,
+                    "type": "pubkey",
+                    "addresses": ["1HWqMzw1jfpXb3xyuUZ4uWXY4tqL2cW47J"]
+                }
+            }
+        ],
+        "hex": "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff08044c86041b020602ffffffff0100f2052a010000004341041b0e8c2567c12536aa13357b79a073dc4444acb83c4ec7a0e2f99dd7457516c5817242da796924ca4e99947d087fedf9ce467cb9f7c6287078f801df276fdf84ac00000000"
+    }"#;
+
+    /// A transaction with a payment output and an OP_RETURN data output.
+    pub(crate) const OP_RETURN_TX: &str = r#"{
+        "txid": "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+        "hash": "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+        "version": 1,
+        "size": 226,
+        "locktime": 0,
+        "vin": [
+            {
+                "txid": "87a157f3fd88ac7907c05fc55e271dc4acdc5605d187d646604ca8c0e9382e03",
+                "vout": 1,
+                "scriptSig": {
+                    "asm": "3044022034519a85fb5299e180865dda936c5d53edabaaf6d15cd1740aac9878b76238e002207345fcb5a62deeb8d9d80e5b412bd24d09151c2008b7fef10eb5f13e484d1e0d[ALL|FORKID] 0266b2a3f8b0e1a5c3e7d3f3bfaa4d1e0b5ef7bd16e1fb14d7b2f5d3b6b4c3e2a1",
+                    "hex": "473044022034519a85fb5299e180865dda936c5d53edabaaf6d15cd1740aac9878b76238e002207345fcb5a62deeb8d9d80e5b412bd24d09151c2008b7fef10eb5f13e484d1e0d41210266b2a3f8b0e1a5c3e7d3f3bfaa4d1e0b5ef7bd16e1fb14d7b2f5d3b6b4c3e2a1"
+                },
+                "sequence": 4294967295
+            }
+        ],
+        "vout": [
+            {
+                "value": 0.00000000,
+                "n": 0,
+                "scriptPubKey": {
+                    "asm": "0 OP_RETURN 48656c6c6f",
+                    "hex": "006a0548656c6c6f",
+                    "type": "nulldata"
+                }
+            },
+            {
+                "value": 20999999.99999999,
+                "n": 1,
+                "scriptPubKey": {
+                    "asm": "OP_DUP OP_HASH160 a1b2c3d4e5f60718293a4b5c6d7e8f9011223344 OP_EQUALVERIFY OP_CHECKSIG",
+                    "hex": "76a914a1b2c3d4e5f60718293a4b5c6d7e8f901122334488ac",
+                    "reqSigs": 1,
+                    "type": "pubkeyhash",
+                    "addresses": ["1FfmbHfnpaZjKFvyi1okTjJJusN455paPH"]
+                }
+            }
+        ]
+    }"#;
+
+    /// Adds the given confirmation fields to a decoded transaction fixture.
+    fn with_metadata(tx: &str, metadata: &str) -> String {
+        let end = tx.rfind('}').unwrap();
+        format!("{},{}}}", &tx[..end], metadata)
+    }
+
+    #[test]
+    fn test_deserialize_confirmed_transaction_info() {
+        let json = with_metadata(
+            COINBASE_TX,
+            r#""blockhash": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+                "confirmations": 12,
+                "time": 1293623863,
+                "blocktime": 1293623863"#,
+        );
+        let info: TransactionInfo = serde_json::from_str(&json).unwrap();
+        assert!(info.is_confirmed());
+        assert_eq!(info.confirmations, Some(12));
+        assert_eq!(
+            info.block_hash.unwrap().to_string(),
+            "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506"
+        );
+        assert_eq!(info.block_time, Some(1293623863));
+        assert_eq!(info.vout[0].value, 5_000_000_000);
+        assert!(info.hex.starts_with("01000000"));
+    }
+
+    #[test]
+    fn test_deserialize_mempool_transaction_info() {
+        // Mempool transactions have no block metadata
+        let info: TransactionInfo = serde_json::from_str(COINBASE_TX).unwrap();
+        assert!(!info.is_confirmed());
+        assert_eq!(info.block_hash, None);
+        assert_eq!(info.confirmations, None);
+        assert_eq!(info.time, None);
+    }
+
+    #[test]
+    fn test_serialize_raw_tx_submission() {
+        let json = serde_json::to_value(RawTxSubmission::new("0100")).unwrap();
+        assert_eq!(json, serde_json::json!({"hex": "0100"}));
+
+        let submission = RawTxSubmission {
+            dont_check_fee: true,
+            ..RawTxSubmission::new("0100")
+        };
+        let json = serde_json::to_value(submission).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"hex": "0100", "dontcheckfee": true})
+        );
+    }
+
+    #[test]
+    fn test_deserialize_tx_out_info() {
+        let out: TxOutInfo = serde_json::from_str(
+            r#"{
+                "bestblock": "000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506",
+                "confirmations": 0,
+                "value": 0.00012345,
+                "scriptPubKey": {
+                    "asm": "OP_DUP OP_HASH160 a1b2c3d4e5f60718293a4b5c6d7e8f9011223344 OP_EQUALVERIFY OP_CHECKSIG",
+                    "hex": "76a914a1b2c3d4e5f60718293a4b5c6d7e8f901122334488ac",
+                    "reqSigs": 1,
+                    "type": "pubkeyhash",
+                    "addresses": ["1FfmbHfnpaZjKFvyi1okTjJJusN455paPH"]
+                },
+                "coinbase": false
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(out.confirmations, 0);
+        assert_eq!(out.value, 12_345);
+        assert_eq!(out.script_pub_key.script_type, "pubkeyhash");
+        assert!(!out.coinbase);
+    }
+
+    #[test]
+    fn test_deserialize_txouts() {
+        let result: TxOutsResult = serde_json::from_str(
+            r#"{"txouts": [
+                {
+                    "scriptPubKey": "76a914a1b2c3d4e5f60718293a4b5c6d7e8f901122334488ac",
+                    "scriptPubKeyLen": 25,
+                    "value": 20999999.99999999,
+                    "isStandard": true,
+                    "confirmations": 6
+                },
+                {"error": "missing"},
+                {"error": "spent", "collidedWith": {"txid": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87"}},
+                {"value": 0.00000546}
+            ]}"#,
+        )
+        .unwrap();
+
+        // Entries stay in request order
+        assert_eq!(result.txouts.len(), 4);
+        let found = result.txouts[0].as_ref().unwrap();
+        assert_eq!(found.value, Some(2_099_999_999_999_999));
+        assert_eq!(found.script_pub_key_len, Some(25));
+        assert_eq!(found.confirmations, Some(6));
+        assert_eq!(result.txouts[1], None);
+        assert_eq!(result.txouts[2], None);
+
+        // Only the requested fields are present
+        let partial = result.txouts[3].as_ref().unwrap();
+        assert_eq!(partial.value, Some(546));
+        assert_eq!(partial.script_pub_key, None);
+    }
+
+    #[test]
+    fn test_serialize_tx_input() {
+        let txid =
+            TxHash::from_hex("87a157f3fd88ac7907c05fc55e271dc4acdc5605d187d646604ca8c0e9382e03")
+                .unwrap();
+        let json = serde_json::to_value(TxInput::new(txid, 1)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "txid": "87a157f3fd88ac7907c05fc55e271dc4acdc5605d187d646604ca8c0e9382e03",
+                "vout": 1
+            })
+        );
+
+        let input = TxInput {
+            sequence: Some(0xfffffffe),
+            ..TxInput::new(txid, 1)
+        };
+        assert_eq!(
+            serde_json::to_value(input).unwrap()["sequence"],
+            0xfffffffeu32
+        );
+    }
+
+    #[test]
+    fn test_deserialize_send_raw_transactions_result() {
+        // All transactions accepted
+        let result: SendRawTransactionsResult = serde_json::from_str("{}").unwrap();
+        assert_eq!(result, SendRawTransactionsResult::default());
+
+        let result: SendRawTransactionsResult = serde_json::from_str(
+            r#"{
+                "evicted": ["8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87"],
+                "invalid": [{
+                    "txid": "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+                    "reject_code": 258,
+                    "reject_reason": "txn-mempool-conflict",
+                    "collidedWith": [{
+                        "txid": "87a157f3fd88ac7907c05fc55e271dc4acdc5605d187d646604ca8c0e9382e03",
+                        "size": 191,
+                        "hex": "0100"
+                    }]
+                }],
+                "unconfirmed": [{
+                    "txid": "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+                    "ancestors": [{
+                        "txid": "87a157f3fd88ac7907c05fc55e271dc4acdc5605d187d646604ca8c0e9382e03",
+                        "vin": [{
+                            "txid": "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+                            "vout": 1
+                        }]
+                    }]
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(result.evicted.len(), 1);
+        assert!(result.known.is_empty());
+        let rejected = &result.invalid[0];
+        assert_eq!(rejected.reject_reason, "txn-mempool-conflict");
+        assert_eq!(
+            rejected.collided_with[0].to_string(),
+            "87a157f3fd88ac7907c05fc55e271dc4acdc5605d187d646604ca8c0e9382e03"
+        );
+        assert_eq!(result.unconfirmed[0].ancestors[0].vin[0].vout, 1);
+    }
+
+    #[test]
+    fn test_deserialize_coinbase_transaction() {
+        let tx: DecodedTransaction = serde_json::from_str(COINBASE_TX).unwrap();
+        assert_eq!(
+            tx.txid.to_string(),
+            "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87"
+        );
+        assert_eq!(tx.vin.len(), 1);
+        assert!(tx.vin[0].is_coinbase());
+        assert_eq!(tx.vin[0].coinbase.as_deref(), Some("044c86041b020602"));
+        assert_eq!(tx.vin[0].txid, None);
+        assert_eq!(tx.vout[0].value, 5_000_000_000);
+        assert_eq!(tx.vout[0].script_pub_key.script_type, "pubkey");
+        assert!(tx.hex.is_some());
+    }
+
+    #[test]
+    fn test_deserialize_op_return_transaction() {
+        let tx: DecodedTransaction = serde_json::from_str(OP_RETURN_TX).unwrap();
+        assert!(!tx.vin[0].is_coinbase());
+        assert_eq!(tx.vin[0].vout, Some(1));
+        assert!(tx.vin[0].script_sig.is_some());
+
+        let op_return = &tx.vout[0];
+        assert_eq!(op_return.value, 0);
+        assert_eq!(op_return.script_pub_key.script_type, "nulldata");
+        assert!(op_return.script_pub_key.addresses.is_empty());
+        assert_eq!(op_return.script_pub_key.req_sigs, None);
+
+        // Exact conversion of a value that f64 can't represent
+        assert_eq!(tx.vout[1].value, 2_099_999_999_999_999);
+        assert_eq!(
+            tx.vout[1].script_pub_key.addresses,
+            vec!["1FfmbHfnpaZjKFvyi1okTjJJusN455paPH".to_string()]
+        );
+        assert_eq!(tx.hex, None);
+    }
+}
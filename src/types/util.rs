@@ -0,0 +1,64 @@
+//! Utility types.
+
+use serde::Deserialize;
+
+/// The node's validation of an address, as returned by `validateaddress`.
+///
+/// An invalid address is reported with `is_valid` set to `false` and the other fields `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AddressValidation {
+    /// Whether the address is valid
+    #[serde(rename = "isvalid")]
+    pub is_valid: bool,
+    /// The validated address
+    pub address: Option<String>,
+    /// The locking script for the address, as hex
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: Option<String>,
+    /// Whether the address belongs to the node's wallet (only reported with a wallet)
+    #[serde(rename = "ismine")]
+    pub is_mine: Option<bool>,
+    /// Whether the address is watch-only in the node's wallet (only reported with a wallet)
+    #[serde(rename = "iswatchonly")]
+    pub is_watch_only: Option<bool>,
+    /// Whether the address is a script (P2SH) address
+    #[serde(rename = "isscript")]
+    pub is_script: Option<bool>,
+}
+
+/// A multisig address, as returned by `createmultisig`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MultisigInfo {
+    /// The P2SH address of the multisig script
+    pub address: String,
+    /// The multisig redeem script, as hex
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_address_validation() {
+        let validation: AddressValidation = serde_json::from_str(
+            r#"{
+                "isvalid": true,
+                "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+                "scriptPubKey": "76a914243f1394f44554f4ce3fd68649c19adc483ce92488ac",
+                "ismine": false,
+                "iswatchonly": false,
+                "isscript": false
+            }"#,
+        )
+        .unwrap();
+        assert!(validation.is_valid);
+        assert_eq!(validation.is_mine, Some(false));
+
+        let validation: AddressValidation = serde_json::from_str(r#"{"isvalid": false}"#).unwrap();
+        assert!(!validation.is_valid);
+        assert_eq!(validation.address, None);
+        assert_eq!(validation.is_mine, None);
+    }
+}
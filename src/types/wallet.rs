@@ -0,0 +1,506 @@
+//! Wallet types.
+
+use super::{de, ser, FeeRate};
+use crate::Error;
+use bitcoinsv::bitcoin::{BlockHash, Encodable, Tx, TxHash};
+use serde::de::{Error as _, IgnoredAny, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+/// Options for `fundrawtransaction`.
+///
+/// Options left as `None` are not sent, so the wallet's defaults apply.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundRawOptions {
+    /// Address to send the change to (a new wallet address by default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<String>,
+    /// Index at which to insert the change output (random by default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// Also select inputs from watch-only addresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_watching: Option<bool>,
+    /// Lock the selected outputs so the wallet doesn't spend them elsewhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// Fee rate in satoshis per kilobyte
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "ser::option_amount"
+    )]
+    pub fee_rate: Option<u64>,
+}
+
+/// A transaction funded by `fundrawtransaction`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FundRawResult {
+    /// The funded, unsigned transaction as hex
+    pub hex: String,
+    /// Fee paid by the transaction, in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub fee: u64,
+    /// Index of the change output, or `None` if no change output was added
+    #[serde(rename = "changepos", deserialize_with = "de::position")]
+    pub change_position: Option<u32>,
+}
+
+/// State of the node's wallet, as returned by `getwalletinfo`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WalletInfo {
+    /// Name of the wallet file
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// Wallet format version
+    #[serde(rename = "walletversion")]
+    pub wallet_version: u64,
+    /// Confirmed balance in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub balance: u64,
+    /// Unconfirmed balance in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub unconfirmed_balance: u64,
+    /// Balance of coinbase outputs that can't be spent yet, in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub immature_balance: u64,
+    /// Number of transactions in the wallet
+    #[serde(rename = "txcount")]
+    pub tx_count: u64,
+    /// Number of pre-generated keys in the key pool
+    #[serde(rename = "keypoolsize")]
+    pub key_pool_size: u64,
+    /// For encrypted wallets, the time until which the wallet is unlocked in seconds since the
+    /// Unix epoch, or 0 if it is locked. `None` if the wallet is not encrypted.
+    #[serde(default)]
+    pub unlocked_until: Option<u64>,
+    /// Fee rate set with `settxfee`, zero if the wallet estimates fees itself
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: FeeRate,
+    /// Id of the HD master key, `None` for non-HD wallets
+    #[serde(rename = "hdmasterkeyid", default)]
+    pub hd_master_key_id: Option<String>,
+}
+
+impl WalletInfo {
+    /// Returns true if the wallet is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.unlocked_until.is_some()
+    }
+
+    /// Returns true if the wallet is encrypted and currently locked, so that a passphrase is
+    /// needed before spending.
+    pub fn is_locked(&self) -> bool {
+        self.unlocked_until == Some(0)
+    }
+}
+
+/// Coins received by a wallet address, as returned by `listreceivedbyaddress`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReceivedByAddress {
+    /// The receiving address
+    pub address: String,
+    /// Total received by the address in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub amount: u64,
+    /// Confirmations of the most recent transaction included
+    pub confirmations: u64,
+    /// Label of the address, empty if it has none
+    #[serde(default)]
+    pub label: String,
+    /// Transactions paying the address
+    #[serde(default, deserialize_with = "de::hashes")]
+    pub txids: Vec<TxHash>,
+}
+
+/// An address in a group returned by `listaddressgroupings`.
+///
+/// The node sends each entry as a positional array, `[address, amount]` or
+/// `[address, amount, label]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressGroupEntry {
+    /// The address
+    pub address: String,
+    /// Balance of the address in satoshis
+    pub amount: u64,
+    /// Label of the address, if it has one
+    pub label: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for AddressGroupEntry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(AddressGroupEntryVisitor)
+    }
+}
+
+/// Visitor that reads an address group entry from its positional array.
+struct AddressGroupEntryVisitor;
+
+impl<'de> Visitor<'de> for AddressGroupEntryVisitor {
+    type Value = AddressGroupEntry;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of address, amount and optional label")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<AddressGroupEntry, A::Error> {
+        let address = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let de::Amount(amount) = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        let label = seq.next_element::<Option<String>>()?.flatten();
+        // Skip any elements added by newer node versions
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(AddressGroupEntry {
+            address,
+            amount,
+            label,
+        })
+    }
+}
+
+/// An unspent wallet output returned by `listunspent`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Unspent {
+    /// Transaction containing the output
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Index of the output in the transaction
+    pub vout: u32,
+    /// Address the output pays to, or `None` for scripts without an address
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Output script as hex
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: String,
+    /// Value of the output in satoshis
+    #[serde(deserialize_with = "de::amount")]
+    pub amount: u64,
+    /// Number of confirmations (0 for mempool outputs)
+    pub confirmations: u64,
+    /// Whether the wallet holds the keys to spend the output
+    pub spendable: bool,
+    /// Whether the wallet knows how to spend the output, ignoring missing keys
+    pub solvable: bool,
+}
+
+/// A wallet transaction entry returned by `listtransactions`.
+///
+/// A transaction that touches several wallet addresses appears once per address.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WalletTxInfo {
+    /// Address sent to or received on, if the output has one
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Kind of entry
+    pub category: WalletTxCategory,
+    /// Amount in satoshis, negative for sends
+    #[serde(deserialize_with = "de::signed_amount")]
+    pub amount: i64,
+    /// Fee in satoshis, negative, only present for sends
+    #[serde(default, deserialize_with = "de::option_signed_amount")]
+    pub fee: Option<i64>,
+    /// Number of confirmations, negative if the transaction conflicts with the main chain
+    #[serde(default)]
+    pub confirmations: i64,
+    /// The transaction, absent for legacy account moves
+    #[serde(default, deserialize_with = "de::option_hash")]
+    pub txid: Option<TxHash>,
+    /// Time the wallet first saw the transaction, in seconds since the Unix epoch
+    pub time: u64,
+    /// Whether a send was abandoned, only present for sends
+    #[serde(default)]
+    pub abandoned: Option<bool>,
+}
+
+/// A wallet transaction returned by `gettransaction`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WalletTransaction {
+    /// The transaction
+    #[serde(deserialize_with = "de::hash")]
+    pub txid: TxHash,
+    /// Net change to the wallet's balance in satoshis, excluding the fee
+    #[serde(deserialize_with = "de::signed_amount")]
+    pub amount: i64,
+    /// Fee in satoshis, negative, only present if the wallet sent the transaction
+    #[serde(default, deserialize_with = "de::option_signed_amount")]
+    pub fee: Option<i64>,
+    /// Number of confirmations, negative if the transaction conflicts with the main chain
+    pub confirmations: i64,
+    /// Block containing the transaction, if it is confirmed
+    #[serde(rename = "blockhash", default, deserialize_with = "de::option_hash")]
+    pub block_hash: Option<BlockHash>,
+    /// Time of the block containing the transaction, if it is confirmed
+    #[serde(rename = "blocktime", default)]
+    pub block_time: Option<u64>,
+    /// Time the wallet first saw the transaction, in seconds since the Unix epoch
+    pub time: u64,
+    /// One entry per wallet address the transaction sends to or receives on
+    pub details: Vec<WalletTxDetail>,
+    /// The serialized transaction as hex
+    pub hex: String,
+}
+
+impl WalletTransaction {
+    /// Parses the serialized transaction.
+    pub fn tx(&self) -> crate::Result<Tx> {
+        let bytes = hex::decode(&self.hex)?;
+        Tx::from_binary(&mut &bytes[..])
+            .map_err(|e| Error::BitcoinSv(format!("Failed to parse transaction: {}", e)))
+    }
+}
+
+/// A per-address entry in the details of a [`WalletTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WalletTxDetail {
+    /// Address sent to or received on, if the output has one
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Kind of entry
+    pub category: WalletTxCategory,
+    /// Amount in satoshis, negative for sends
+    #[serde(deserialize_with = "de::signed_amount")]
+    pub amount: i64,
+    /// Fee in satoshis, negative, only present for sends
+    #[serde(default, deserialize_with = "de::option_signed_amount")]
+    pub fee: Option<i64>,
+    /// Index of the output in the transaction
+    pub vout: u32,
+    /// Whether a send was abandoned, only present for sends
+    #[serde(default)]
+    pub abandoned: Option<bool>,
+}
+
+/// Category of a wallet transaction entry.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum WalletTxCategory {
+    /// Coins sent from the wallet
+    Send,
+    /// Coins received by the wallet
+    Receive,
+    /// A mature coinbase output
+    Generate,
+    /// A coinbase output that can't be spent yet
+    Immature,
+    /// A coinbase output from a block that is no longer on the main chain
+    Orphan,
+    /// A category this library does not know about
+    Other(String),
+}
+
+impl From<String> for WalletTxCategory {
+    fn from(category: String) -> Self {
+        match category.as_str() {
+            "send" => WalletTxCategory::Send,
+            "receive" => WalletTxCategory::Receive,
+            "generate" => WalletTxCategory::Generate,
+            "immature" => WalletTxCategory::Immature,
+            "orphan" => WalletTxCategory::Orphan,
+            _ => WalletTxCategory::Other(category),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_fund_raw_options() {
+        let json = serde_json::to_string(&FundRawOptions::default()).unwrap();
+        assert_eq!(json, "{}");
+
+        let options = FundRawOptions {
+            change_address: Some("mzChange".to_string()),
+            include_watching: Some(false),
+            fee_rate: Some(1_000),
+            ..FundRawOptions::default()
+        };
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            json,
+            r#"{"changeAddress":"mzChange","includeWatching":false,"feeRate":0.00001000}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_fund_raw_result_without_change() {
+        let result: FundRawResult =
+            serde_json::from_str(r#"{"hex": "0100", "fee": 0.0000025, "changepos": -1}"#).unwrap();
+        assert_eq!(result.fee, 250);
+        assert_eq!(result.change_position, None);
+    }
+
+    #[test]
+    fn test_deserialize_unspent() {
+        let json = r#"{
+            "txid": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+            "vout": 1,
+            "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+            "account": "",
+            "scriptPubKey": "76a914243f1394f44554f4ce3fd68649c19adc483ce92488ac",
+            "amount": 20999999.99999999,
+            "confirmations": 101,
+            "spendable": true,
+            "solvable": true,
+            "safe": true
+        }"#;
+        let unspent: Unspent = serde_json::from_str(json).unwrap();
+        assert_eq!(unspent.vout, 1);
+        assert_eq!(
+            unspent.address.as_deref(),
+            Some("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn")
+        );
+        assert_eq!(unspent.amount, 2_099_999_999_999_999);
+        assert_eq!(unspent.confirmations, 101);
+        assert!(unspent.spendable && unspent.solvable);
+    }
+
+    #[test]
+    fn test_deserialize_wallet_tx_info() {
+        let json = r#"[{
+            "account": "",
+            "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+            "category": "send",
+            "amount": -1.00000001,
+            "vout": 0,
+            "fee": -0.0000025,
+            "confirmations": 3,
+            "blockhash": "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206",
+            "txid": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+            "time": 1700000000,
+            "timereceived": 1700000000,
+            "abandoned": false
+        }, {
+            "account": "",
+            "category": "move",
+            "amount": 0.5,
+            "time": 1700000001,
+            "otheraccount": "savings"
+        }]"#;
+        let entries: Vec<WalletTxInfo> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(entries[0].category, WalletTxCategory::Send);
+        assert_eq!(entries[0].amount, -100_000_001);
+        assert_eq!(entries[0].fee, Some(-250));
+        assert_eq!(entries[0].abandoned, Some(false));
+        assert!(entries[0].txid.is_some());
+
+        assert_eq!(
+            entries[1].category,
+            WalletTxCategory::Other("move".to_string())
+        );
+        assert_eq!(entries[1].txid, None);
+        assert_eq!(entries[1].fee, None);
+    }
+
+    #[test]
+    fn test_deserialize_wallet_info_unencrypted() {
+        let json = r#"{
+            "walletname": "wallet.dat",
+            "walletversion": 130000,
+            "balance": 21000000.00000001,
+            "unconfirmed_balance": 0,
+            "immature_balance": 1250.0,
+            "txcount": 104,
+            "keypoololdest": 1700000000,
+            "keypoolsize": 1000,
+            "paytxfee": 0.00002,
+            "hdmasterkeyid": "4d2b5c1aa1c14a6a0d8d7f7b6a4a2cb7d6b3c1e0"
+        }"#;
+        let info: WalletInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.balance, 2_100_000_000_000_001);
+        assert_eq!(info.immature_balance, 125_000_000_000);
+        assert_eq!(info.pay_tx_fee, FeeRate::from_sats_per_kb(2000));
+        assert_eq!(info.unlocked_until, None);
+        assert!(!info.is_encrypted());
+        assert!(!info.is_locked());
+    }
+
+    #[test]
+    fn test_deserialize_wallet_info_encrypted() {
+        let json = r#"{
+            "walletname": "wallet.dat",
+            "walletversion": 130000,
+            "balance": 0,
+            "unconfirmed_balance": 0,
+            "immature_balance": 0,
+            "txcount": 0,
+            "keypoolsize": 1000,
+            "unlocked_until": 0,
+            "paytxfee": 0
+        }"#;
+        let info: WalletInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.unlocked_until, Some(0));
+        assert_eq!(info.hd_master_key_id, None);
+        assert!(info.is_encrypted());
+        assert!(info.is_locked());
+
+        let json = json.replace(r#""unlocked_until": 0"#, r#""unlocked_until": 1700000060"#);
+        let info: WalletInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(info.unlocked_until, Some(1_700_000_060));
+        assert!(info.is_encrypted());
+        assert!(!info.is_locked());
+    }
+
+    #[test]
+    fn test_deserialize_received_by_address() {
+        let json = r#"[{
+            "address": "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+            "account": "invoices",
+            "amount": 0.1,
+            "confirmations": 12,
+            "label": "invoices",
+            "txids": ["4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"]
+        }, {
+            "involvesWatchonly": true,
+            "address": "mzEmpty",
+            "amount": 0,
+            "confirmations": 0,
+            "txids": []
+        }]"#;
+        let received: Vec<ReceivedByAddress> = serde_json::from_str(json).unwrap();
+        assert_eq!(received[0].amount, 10_000_000);
+        assert_eq!(received[0].label, "invoices");
+        assert_eq!(
+            received[0].txids[0].to_string(),
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        );
+        assert_eq!(received[1].label, "");
+        assert!(received[1].txids.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_address_groupings() {
+        let json = r#"[
+            [
+                ["mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", 21000000.00000001, "savings"],
+                ["mzChange", 0.0000025]
+            ],
+            [
+                ["mzOther", 0, ""]
+            ]
+        ]"#;
+        let groups: Vec<Vec<AddressGroupEntry>> = serde_json::from_str(json).unwrap();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            groups[0][0],
+            AddressGroupEntry {
+                address: "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn".to_string(),
+                amount: 2_100_000_000_000_001,
+                label: Some("savings".to_string()),
+            }
+        );
+        assert_eq!(groups[0][1].amount, 250);
+        assert_eq!(groups[0][1].label, None);
+        assert_eq!(groups[1][0].label.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_deserialize_address_group_entry_too_short() {
+        assert!(serde_json::from_str::<AddressGroupEntry>(r#"["mzChange"]"#).is_err());
+    }
+}
@@ -16,8 +16,13 @@
 //! export BSV_NODE_PASSWORD=password
 //! cargo test --test integration_tests -- --ignored
 //! ```
+//!
+//! Tests that change the state of the node (e.g. invalidating blocks) only run when the node
+//! is on regtest, and are skipped otherwise. Tests that mine their own blocks are not ignored:
+//! they run whenever a regtest node is reachable and are skipped when there is none.
 
-use bitcoinsv_rpc::{NodeClient, SvNodeClient};
+use bitcoinsv_rpc::types::{FeeRate, Network, TargetType};
+use bitcoinsv_rpc::{Error, NodeClient, SvNodeClient};
 
 /// Helper to get node connection details from environment
 fn get_node_config() -> (String, Option<String>, Option<String>) {
@@ -34,6 +39,45 @@ fn create_test_client() -> SvNodeClient {
     SvNodeClient::new(&url, user, password).expect("Failed to create client")
 }
 
+/// Helper to create a client for tests that change the node's state.
+///
+/// Returns `None`, so that the test can be skipped, unless the node is running on regtest.
+async fn create_regtest_client() -> Option<SvNodeClient> {
+    let client = create_test_client();
+    let info = client
+        .get_blockchain_info()
+        .await
+        .expect("Failed to get blockchain info");
+    if info.chain == "regtest" {
+        Some(client)
+    } else {
+        println!("Skipping test: node is on {}, not regtest", info.chain);
+        None
+    }
+}
+
+/// Helper to create a client for self-contained tests that don't need to be ignored.
+///
+/// Returns `None`, so that the test can be skipped, if no node is reachable or the node is not
+/// running on regtest.
+async fn try_regtest_client() -> Option<SvNodeClient> {
+    let client = create_test_client();
+    match client.get_blockchain_info().await {
+        Ok(info) if info.chain == "regtest" => Some(client),
+        Ok(info) => {
+            println!("Skipping test: node is on {}, not regtest", info.chain);
+            None
+        }
+        Err(e) => {
+            println!("Skipping test: no node available ({})", e);
+            None
+        }
+    }
+}
+
+/// A regtest address to mine to (regtest shares the testnet address prefix).
+const REGTEST_ADDRESS: &str = "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn";
+
 #[tokio::test]
 #[ignore] // Run with: cargo test --test integration_tests -- --ignored
 async fn test_get_best_block_hash() {
@@ -57,6 +101,189 @@ async fn test_get_best_block_hash() {
     println!("Best block hash: {}", hash);
 }
 
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_block_count() {
+    let client = create_test_client();
+
+    let result = client.get_block_count().await;
+    assert!(
+        result.is_ok(),
+        "Failed to get block count: {:?}",
+        result.err()
+    );
+
+    let count = result.unwrap();
+    assert!(count > 0, "Block count should be positive");
+
+    println!("Block count: {}", count);
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_block_hash() {
+    let client = create_test_client();
+
+    let height = client
+        .get_block_count()
+        .await
+        .expect("Failed to get block count");
+    let best_hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+
+    // The block at the tip height should be the best block
+    let hash = client
+        .get_block_hash(height)
+        .await
+        .expect("Failed to get block hash");
+    assert_eq!(
+        hash, best_hash,
+        "Hash at tip height should be the best block"
+    );
+
+    // A height beyond the tip should give a typed error
+    let result = client.get_block_hash(height + 1000).await;
+    assert!(
+        matches!(result, Err(Error::BlockHeightOutOfRange(_))),
+        "Expected BlockHeightOutOfRange, got {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_blockchain_info() {
+    let client = create_test_client();
+
+    let info = client
+        .get_blockchain_info()
+        .await
+        .expect("Failed to get blockchain info");
+
+    println!("Blockchain info: {:?}", info);
+    assert!(
+        info.headers >= info.blocks,
+        "Headers should be at least blocks"
+    );
+    assert!(info.difficulty > 0.0, "Difficulty should be positive");
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_chain_tips() {
+    use bitcoinsv_rpc::types::ChainTipStatus;
+
+    let client = create_test_client();
+
+    let tips = client
+        .get_chain_tips()
+        .await
+        .expect("Failed to get chain tips");
+    let best_hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+
+    let active: Vec<_> = tips
+        .iter()
+        .filter(|tip| tip.status == ChainTipStatus::Active)
+        .collect();
+    assert_eq!(active.len(), 1, "There should be exactly one active tip");
+    assert_eq!(
+        active[0].hash, best_hash,
+        "Active tip should be the best block"
+    );
+    assert_eq!(active[0].branch_len, 0);
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_verify_chain() {
+    let client = create_test_client();
+
+    // Keep the check small so the test doesn't take minutes
+    let result = client.verify_chain(Some(1), Some(2)).await;
+    assert!(result.is_ok(), "Failed to verify chain: {:?}", result.err());
+    assert!(result.unwrap(), "Chain should verify");
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_invalidate_and_reconsider_block() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let original = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+
+    client
+        .invalidate_block(&original)
+        .await
+        .expect("Failed to invalidate block");
+    let after_invalidate = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    assert_ne!(
+        after_invalidate, original,
+        "Tip should change after invalidating it"
+    );
+
+    client
+        .reconsider_block(&original)
+        .await
+        .expect("Failed to reconsider block");
+    let after_reconsider = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    assert_eq!(
+        after_reconsider, original,
+        "Tip should be restored after reconsidering"
+    );
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_wait_for_new_block_timeout() {
+    let client = create_test_client();
+
+    let best_hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+
+    // With a short timeout the node returns the current tip
+    let tip = client
+        .wait_for_new_block(Some(100))
+        .await
+        .expect("Failed to wait for new block");
+    println!("Tip after waiting: {} at height {}", tip.hash, tip.height);
+    assert!(tip.height > 0);
+    if tip.hash != best_hash {
+        println!("A new block arrived while waiting");
+    }
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_difficulty() {
+    let client = create_test_client();
+
+    let difficulty = client
+        .get_difficulty()
+        .await
+        .expect("Failed to get difficulty");
+
+    println!("Difficulty: {}", difficulty);
+    assert!(difficulty > 0.0, "Difficulty should be positive");
+}
+
 #[tokio::test]
 #[ignore] // Run with: cargo test --test integration_tests -- --ignored
 async fn test_get_block_header() {
@@ -91,6 +318,33 @@ async fn test_get_block_header() {
     assert!(header.timestamp() > 0, "Block timestamp should be positive");
 }
 
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_block_header_info() {
+    let client = create_test_client();
+
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let height = client
+        .get_block_count()
+        .await
+        .expect("Failed to get block count");
+
+    let info = client
+        .get_block_header_info(&hash)
+        .await
+        .expect("Failed to get block header info");
+
+    assert_eq!(info.hash, hash);
+    assert_eq!(info.header.hash(), hash);
+    assert_eq!(info.height, height);
+    assert!(info.in_main_chain, "Tip should be on the main chain");
+    assert_eq!(info.confirmations, Some(1));
+    assert!(info.next_block_hash.is_none(), "Tip has no next block");
+}
+
 #[tokio::test]
 #[ignore] // Run with: cargo test --test integration_tests -- --ignored
 async fn test_get_block() {
@@ -121,6 +375,682 @@ async fn test_get_block() {
     assert!(header.version() > 0, "Block version should be positive");
 }
 
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_block_info() {
+    let client = create_test_client();
+
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+
+    let info = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+
+    assert_eq!(info.hash, hash);
+    assert!(
+        !info.tx.is_empty(),
+        "Block should have a coinbase transaction"
+    );
+    if let Some(num_tx) = info.num_tx {
+        assert_eq!(
+            info.tx.len() as u64,
+            num_tx,
+            "Block info should list every transaction in the block"
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_raw_transaction() {
+    let client = create_test_client();
+
+    // The coinbase of the tip block has unspent outputs, so the node can find it even
+    // without -txindex
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let info = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+    let txid = info.tx[0];
+
+    let tx = client
+        .get_raw_transaction(&txid)
+        .await
+        .expect("Failed to get raw transaction");
+    assert_eq!(
+        tx.hash(),
+        txid,
+        "Transaction should hash to the requested id"
+    );
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_raw_transaction_info() {
+    let client = create_test_client();
+
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let block = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+    let txid = block.tx[0];
+
+    let info = client
+        .get_raw_transaction_info(&txid)
+        .await
+        .expect("Failed to get raw transaction info");
+    assert_eq!(info.txid, txid);
+    assert_eq!(info.block_hash, Some(hash));
+    assert!(
+        info.is_confirmed(),
+        "Transaction in the tip block should be confirmed"
+    );
+    assert!(info.vin[0].is_coinbase());
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_decode_raw_transaction() {
+    let client = create_test_client();
+
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let block = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+    let info = client
+        .get_raw_transaction_info(&block.tx[0])
+        .await
+        .expect("Failed to get raw transaction info");
+
+    let decoded = client
+        .decode_raw_transaction(&info.hex)
+        .await
+        .expect("Failed to decode transaction");
+    assert_eq!(decoded.txid, info.txid);
+    assert_eq!(decoded.vin, info.vin);
+    assert_eq!(decoded.vout, info.vout);
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_tx_out() {
+    let client = create_test_client();
+
+    // The tip's coinbase is immature, so its first output can't have been spent
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let block = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+    let txid = block.tx[0];
+
+    let out = client
+        .get_tx_out(&txid, 0, false)
+        .await
+        .expect("Failed to get tx out")
+        .expect("Coinbase output should be unspent");
+    assert!(out.coinbase);
+
+    let missing = client
+        .get_tx_out(&txid, u32::MAX, false)
+        .await
+        .expect("Failed to get tx out");
+    assert!(missing.is_none(), "Nonexistent output should be None");
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_tx_out_proof_round_trip() {
+    let client = create_test_client();
+
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let block = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+    let txid = block.tx[0];
+
+    let proof = client
+        .get_tx_out_proof(&[txid], Some(&hash))
+        .await
+        .expect("Failed to get tx out proof");
+    let proven = client
+        .verify_tx_out_proof(&proof)
+        .await
+        .expect("Failed to verify tx out proof");
+    assert_eq!(proven, vec![txid]);
+
+    // Corrupt the first transaction hash in the proof, which follows the 80-byte header, the
+    // 4-byte transaction count and the 1-byte hash count
+    let mut tampered = proof.clone();
+    tampered[85] ^= 0xff;
+    assert!(matches!(
+        client.verify_tx_out_proof(&tampered).await,
+        Err(Error::InvalidProof(_))
+    ));
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_merkle_proof2_round_trip() {
+    let client = create_test_client();
+
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let block = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+
+    let proof = client
+        .get_merkle_proof2(&hash, &block.tx[0], false, TargetType::Header)
+        .await
+        .expect("Failed to get merkle proof");
+    assert_eq!(proof.index, 0);
+    assert!(client
+        .verify_merkle_proof(&proof)
+        .await
+        .expect("Failed to verify merkle proof"));
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_mempool_info() {
+    let client = create_test_client();
+
+    let info = client
+        .get_mempool_info()
+        .await
+        .expect("Failed to get mempool info");
+    assert!(info.max_mempool > 0, "Mempool limit should be positive");
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_raw_mempool() {
+    let client = create_test_client();
+
+    // Just checks that the listing parses; the mempool may legitimately be empty
+    client
+        .get_raw_mempool()
+        .await
+        .expect("Failed to get raw mempool");
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_mempool_entry_not_found() {
+    let client = create_test_client();
+
+    // A confirmed transaction is never in the mempool
+    let hash = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let block = client
+        .get_block_info(&hash)
+        .await
+        .expect("Failed to get block info");
+
+    match client.get_mempool_entry(&block.tx[0]).await {
+        Err(Error::NotFound(_)) => {}
+        other => panic!("Expected NotFound, got {:?}", other.map(|e| e.size)),
+    }
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_mining_info() {
+    let client = create_test_client();
+
+    let info = client
+        .get_mining_info()
+        .await
+        .expect("Failed to get mining info");
+    let blockchain = client
+        .get_blockchain_info()
+        .await
+        .expect("Failed to get blockchain info");
+    assert_eq!(info.chain, Network::from(blockchain.chain));
+}
+
+#[tokio::test]
+async fn test_generate_to_address() {
+    let Some(client) = try_regtest_client().await else {
+        return;
+    };
+
+    let height = client
+        .get_block_count()
+        .await
+        .expect("Failed to get block count");
+    let hashes = client
+        .generate_to_address(2, REGTEST_ADDRESS, None)
+        .await
+        .expect("Failed to generate blocks");
+    assert_eq!(hashes.len(), 2);
+
+    let tip = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    assert_eq!(tip, hashes[1], "Last generated block should be the tip");
+    let new_height = client
+        .get_block_count()
+        .await
+        .expect("Failed to get block count");
+    assert_eq!(new_height, height + 2);
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_set_network_active() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let active = client
+        .set_network_active(false)
+        .await
+        .expect("Failed to disable networking");
+    assert!(!active, "Networking should be disabled");
+
+    let active = client
+        .set_network_active(true)
+        .await
+        .expect("Failed to enable networking");
+    assert!(active, "Networking should be enabled again");
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_validate_address() {
+    let client = create_test_client();
+
+    let validation = client
+        .validate_address("not-an-address")
+        .await
+        .expect("Failed to validate address");
+    assert!(!validation.is_valid);
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_verify_message() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let address = match client.get_new_address(None).await {
+        Ok(address) => address,
+        Err(Error::WalletUnavailable) => {
+            println!("Skipping test: node has no wallet");
+            return;
+        }
+        Err(e) => panic!("Failed to get new address: {:?}", e),
+    };
+    let message = "bitcoinsv-rpc signed message";
+    let signature = client
+        .sign_message(&address, message)
+        .await
+        .expect("Failed to sign message");
+
+    let valid = client
+        .verify_message(&address, &signature, message)
+        .await
+        .expect("Failed to verify message");
+    assert!(valid, "Signature should verify");
+
+    let tampered = client
+        .verify_message(&address, &signature, "a different message")
+        .await
+        .expect("Failed to verify message");
+    assert!(!tampered, "Signature should not verify for another message");
+
+    let malformed = client
+        .verify_message(&address, "not base64!", message)
+        .await
+        .expect("Malformed signature should not be an error");
+    assert!(!malformed);
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_set_tx_fee() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let address = match client.get_new_address(None).await {
+        Ok(address) => address,
+        Err(Error::WalletUnavailable) => {
+            println!("Skipping test: node has no wallet");
+            return;
+        }
+        Err(e) => panic!("Failed to get new address: {:?}", e),
+    };
+
+    let fee_rate = FeeRate::from_sats_per_kb(2000);
+    let accepted = client
+        .set_tx_fee(fee_rate)
+        .await
+        .expect("Failed to set wallet fee");
+    assert!(accepted);
+
+    // Check the rate is applied to a wallet send
+    client
+        .generate_to_address(101, &address, None)
+        .await
+        .expect("Failed to generate blocks");
+    let txid = client
+        .send_to_address(REGTEST_ADDRESS, 100_000_000, None, false)
+        .await
+        .expect("Failed to send to address");
+    let wallet_tx = client
+        .get_wallet_transaction(&txid, false)
+        .await
+        .expect("Failed to get wallet transaction");
+
+    // The wallet sizes the fee from its signature size estimate, which can be a few bytes
+    // larger than the signatures it produces
+    let size = wallet_tx.hex.len() as u64 / 2;
+    let paid = wallet_tx
+        .fee
+        .expect("Send should have a fee")
+        .unsigned_abs();
+    assert!(
+        paid >= fee_rate.sats_per_kb() * size / 1000 && paid <= fee_rate.fee_for_size(size + 4),
+        "Fee {} doesn't match {} for {} bytes",
+        paid,
+        fee_rate,
+        size
+    );
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_new_address() {
+    let client = create_test_client();
+
+    let address = match client.get_new_address(None).await {
+        Ok(address) => address,
+        Err(Error::WalletUnavailable) => {
+            println!("Skipping test: node has no wallet");
+            return;
+        }
+        Err(e) => panic!("Failed to get new address: {:?}", e),
+    };
+    let validation = client
+        .validate_address(&address)
+        .await
+        .expect("Failed to validate address");
+    assert!(validation.is_valid);
+    assert_eq!(validation.is_mine, Some(true));
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_send_to_address() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let address = match client.get_new_address(None).await {
+        Ok(address) => address,
+        Err(Error::WalletUnavailable) => {
+            println!("Skipping test: node has no wallet");
+            return;
+        }
+        Err(e) => panic!("Failed to get new address: {:?}", e),
+    };
+    // Coinbase outputs need 100 confirmations before the wallet can spend them
+    client
+        .generate_to_address(101, &address, None)
+        .await
+        .expect("Failed to generate blocks");
+
+    let txid = client
+        .send_to_address(REGTEST_ADDRESS, 100_000_000, None, false)
+        .await
+        .expect("Failed to send to address");
+    let mempool = client
+        .get_raw_mempool()
+        .await
+        .expect("Failed to get mempool");
+    assert!(
+        mempool.contains(&txid),
+        "Sent transaction should be in the mempool"
+    );
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_import_address_watch_only() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let funding_address = match client.get_new_address(None).await {
+        Ok(address) => address,
+        Err(Error::WalletUnavailable) => {
+            println!("Skipping test: node has no wallet");
+            return;
+        }
+        Err(e) => panic!("Failed to get new address: {:?}", e),
+    };
+    client
+        .import_address(REGTEST_ADDRESS, Some("watched"), false, false)
+        .await
+        .expect("Failed to import address");
+
+    client
+        .generate_to_address(101, &funding_address, None)
+        .await
+        .expect("Failed to generate blocks");
+    let txid = client
+        .send_to_address(REGTEST_ADDRESS, 100_000_000, None, false)
+        .await
+        .expect("Failed to send to address");
+    client
+        .generate_to_address(1, &funding_address, None)
+        .await
+        .expect("Failed to generate block");
+
+    let watched = [REGTEST_ADDRESS.to_string()];
+    let unspent = client
+        .list_unspent(None, None, Some(&watched), true)
+        .await
+        .expect("Failed to list unspent outputs");
+    let output = unspent
+        .iter()
+        .find(|u| u.txid == txid)
+        .expect("Watch-only output should be listed");
+    assert_eq!(output.amount, 100_000_000);
+    assert!(
+        !output.spendable,
+        "Watch-only output should not be spendable"
+    );
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_sign_and_verify_message() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let address = match client.get_new_address(None).await {
+        Ok(address) => address,
+        Err(Error::WalletUnavailable) => {
+            println!("Skipping test: node has no wallet");
+            return;
+        }
+        Err(e) => panic!("Failed to get new address: {:?}", e),
+    };
+    let message = "bitcoinsv-rpc round trip";
+
+    let signature = client
+        .sign_message(&address, message)
+        .await
+        .expect("Failed to sign message");
+    let valid = client
+        .verify_message(&address, &signature, message)
+        .await
+        .expect("Failed to verify message");
+    assert!(valid, "Wallet signature should verify");
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_lock_unspent() {
+    let Some(client) = create_regtest_client().await else {
+        return;
+    };
+
+    let address = match client.get_new_address(None).await {
+        Ok(address) => address,
+        Err(Error::WalletUnavailable) => {
+            println!("Skipping test: node has no wallet");
+            return;
+        }
+        Err(e) => panic!("Failed to get new address: {:?}", e),
+    };
+    client
+        .generate_to_address(101, &address, None)
+        .await
+        .expect("Failed to generate blocks");
+
+    let unspent = client
+        .list_unspent(None, None, None, true)
+        .await
+        .expect("Failed to list unspent outputs");
+    let output = unspent
+        .first()
+        .expect("Wallet should have a spendable output");
+    let outpoint = (output.txid, output.vout);
+
+    assert!(client
+        .lock_unspent(false, &[outpoint])
+        .await
+        .expect("Failed to lock output"));
+    let locked = client
+        .list_lock_unspent()
+        .await
+        .expect("Failed to list locked outputs");
+    assert!(locked.contains(&outpoint));
+    let unspent = client
+        .list_unspent(None, None, None, true)
+        .await
+        .expect("Failed to list unspent outputs");
+    assert!(
+        !unspent.iter().any(|u| (u.txid, u.vout) == outpoint),
+        "Locked output should not be listed"
+    );
+
+    assert!(client
+        .lock_unspent(true, &[outpoint])
+        .await
+        .expect("Failed to unlock output"));
+    let unspent = client
+        .list_unspent(None, None, None, true)
+        .await
+        .expect("Failed to list unspent outputs");
+    assert!(unspent.iter().any(|u| (u.txid, u.vout) == outpoint));
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_block_headers() {
+    let client = create_test_client();
+
+    let genesis = client
+        .get_block_hash(0)
+        .await
+        .expect("Failed to get genesis hash");
+    let headers = client
+        .get_block_headers(&genesis, 10)
+        .await
+        .expect("Failed to get headers over REST");
+    assert!(!headers.is_empty() && headers.len() <= 10);
+    assert_eq!(headers[0].hash(), genesis);
+    for (height, header) in headers.iter().enumerate() {
+        let hash = client
+            .get_block_hash(height as u64)
+            .await
+            .expect("Failed to get block hash");
+        assert_eq!(
+            header.hash(),
+            hash,
+            "Header {} should be in the best chain",
+            height
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_get_block_info_rest() {
+    let client = create_test_client();
+
+    let best = client
+        .get_best_block_hash()
+        .await
+        .expect("Failed to get best block hash");
+    let rest = client
+        .get_block_info_rest(&best)
+        .await
+        .expect("Failed to get block info over REST");
+    let rpc = client
+        .get_block_info(&best)
+        .await
+        .expect("Failed to get block info over RPC");
+    assert_eq!(rest.hash, best);
+    assert_eq!(rest.height, rpc.height);
+    assert_eq!(rest.tx, rpc.tx);
+}
+
+#[tokio::test]
+#[ignore] // Run with: cargo test --test integration_tests -- --ignored
+async fn test_send_raw_transaction_rejected() {
+    let client = create_test_client();
+
+    // A truncated transaction fails to decode and must not be broadcast
+    match client
+        .send_raw_transaction("0100000001", false, false)
+        .await
+    {
+        Err(Error::Rpc { code, .. }) => assert_eq!(code, -22, "Expected a decode error"),
+        other => panic!(
+            "Expected an RPC error, got {:?}",
+            other.map(|t| t.to_string())
+        ),
+    }
+}
+
 #[tokio::test]
 #[ignore] // Run with: cargo test --test integration_tests -- --ignored
 async fn test_block_header_consistency() {